@@ -0,0 +1,52 @@
+//! Moving the camera to an absolute position
+use crate::{capabilities::Range, error, record, Cam, CamResponse};
+
+
+/// Full turn, used when the camera does not report the azimuth range
+const DEFAULT_AZIMUTH_RANGE: Range = Range { min: 0, max: 3600 };
+/// From looking straight down to the horizon, used when the camera does not report the elevation range
+const DEFAULT_ELEVATION_RANGE: Range = Range { min: -900, max: 900 };
+
+
+impl Cam {
+    /// Move the camera to an absolute position. `azimuth` and `elevation` are in tenths of a degree,
+    /// `zoom` is the zoom factor multiplied by ten, the same units as in `PtzStatus`
+    pub async fn move_absolute(&mut self, azimuth: i32, elevation: i32, zoom: i32) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        self.record(record::RecordedAction::Absolute { azimuth, elevation, zoom });
+        let res = self.send(self.client.put(self.ptz_url("absolute")).body(format!("<PTZData>
+                <AbsoluteHigh>
+                    <elevation>{}</elevation>
+                    <azimuth>{}</azimuth>
+                    <absoluteZoom>{}</absoluteZoom>
+                </AbsoluteHigh>
+            </PTZData>", elevation, azimuth, zoom))).await?;
+
+        Self::check_supported(res, "absolute").await
+    }
+
+    /// Point the camera to `azimuth_deg` and `elevation_deg` degrees keeping the current zoom. The degrees are rounded
+    /// to tenths and must be finite and inside the mechanical range from the capabilities of the camera
+    pub async fn point_at(&mut self, azimuth_deg: f32, elevation_deg: f32) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let capabilities = if self.capabilities_available {
+            self.get_capabilities().await?
+        } else {
+            Default::default()
+        };
+
+        let azimuth = (azimuth_deg * 10.0).round() as i32;
+        let azimuth_range = capabilities.absolute_pan.unwrap_or(DEFAULT_AZIMUTH_RANGE);
+        if !azimuth_deg.is_finite() || !azimuth_range.contains(azimuth) {
+            return Err(Box::new(error::OutOfRangeDegreesError::new("azimuth", azimuth_deg, azimuth_range)));
+        }
+
+        let elevation = (elevation_deg * 10.0).round() as i32;
+        let elevation_range = capabilities.absolute_tilt.unwrap_or(DEFAULT_ELEVATION_RANGE);
+        if !elevation_deg.is_finite() || !elevation_range.contains(elevation) {
+            return Err(Box::new(error::OutOfRangeDegreesError::new("elevation", elevation_deg, elevation_range)));
+        }
+
+        let zoom = self.get_status().await?.absolute_zoom;
+
+        self.move_absolute(azimuth, elevation, zoom).await
+    }
+}
@@ -0,0 +1,193 @@
+//! HTTP authentication, basic or digest depending on what the camera supports
+use std::{sync::{Arc, Mutex}, time::{SystemTime, UNIX_EPOCH}};
+use reqwest::{header::{HeaderMap, HeaderValue, AUTHORIZATION, WWW_AUTHENTICATE}, Request, RequestBuilder, Response, StatusCode};
+use crate::{xml, Cam};
+
+
+/// Scheme of the HTTP authentication, `build` reads it from the security capabilities of the camera
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AuthScheme {
+    /// Basic authentication, switching to digest when the camera asks for it. Used when the camera supports both
+    /// or does not have the security capabilities
+    #[default]
+    Auto,
+    /// Only basic authentication
+    Basic,
+    /// Digest authentication, the last challenge of the camera is answered in advance
+    Digest,
+}
+
+
+impl AuthScheme {
+    pub(crate) fn parse(body: &str) -> Self {
+        match xml::tag_value(body, "CertificateType") {
+            Some("basic") => Self::Basic,
+            Some("digest") => Self::Digest,
+            _ => Self::Auto,
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+struct Challenge {
+    realm: String,
+    nonce: String,
+    qop: Option<String>,
+    opaque: Option<String>,
+    nonce_count: u32,
+}
+
+
+impl Challenge {
+    fn parse(header: &str) -> Option<Self> {
+        let params = header.strip_prefix("Digest ")?;
+        let mut challenge = Self {
+            realm: String::new(),
+            nonce: String::new(),
+            qop: None,
+            opaque: None,
+            nonce_count: 0,
+        };
+
+        for (key, value) in Self::params(params) {
+            match key {
+                "realm" => challenge.realm = value,
+                "nonce" => challenge.nonce = value,
+                "qop" => challenge.qop = Some(value),
+                "opaque" => challenge.opaque = Some(value),
+                _ => {}
+            }
+        }
+
+        (!challenge.nonce.is_empty()).then_some(challenge)
+    }
+
+    fn params(params: &str) -> Vec<(&str, String)> {
+        let mut parsed = Vec::new();
+        let mut rest = params.trim();
+
+        while let Some((key, value)) = rest.split_once('=') {
+            let key = key.trim().trim_start_matches(',').trim();
+
+            let (value, next) = match value.strip_prefix('"') {
+                Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+                None => value.split_once(',').unwrap_or((value, "")),
+            };
+
+            parsed.push((key, value.to_string()));
+            rest = next;
+        }
+
+        parsed
+    }
+}
+
+
+/// Last digest challenge of the camera, clones of `Cam` share it
+#[derive(Clone, Debug, Default)]
+pub(crate) struct DigestState(Arc<Mutex<Option<Challenge>>>);
+
+
+impl DigestState {
+    /// Remember the digest challenge from the headers of a 401 answer, returns whether there was one
+    fn update(&self, headers: &HeaderMap) -> bool {
+        let challenge = headers.get_all(WWW_AUTHENTICATE).iter()
+            .filter_map(|header| header.to_str().ok())
+            .find_map(Challenge::parse);
+
+        match challenge {
+            Some(challenge) => {
+                *self.0.lock().unwrap_or_else(|err| err.into_inner()) = Some(challenge);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn authorize(&self, request: &mut Request, user: &str, passwd: &str) {
+        let mut state = self.0.lock().unwrap_or_else(|err| err.into_inner());
+        let Some(challenge) = state.as_mut() else {
+            return;
+        };
+
+        challenge.nonce_count += 1;
+        let nonce_count = format!("{:08x}", challenge.nonce_count);
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
+        let cnonce = format!("{:016x}", nanos as u64 ^ challenge.nonce_count as u64);
+
+        let uri = match request.url().query() {
+            Some(query) => format!("{}?{}", request.url().path(), query),
+            None => request.url().path().to_string(),
+        };
+        let ha1 = md5::compute(format!("{}:{}:{}", user, challenge.realm, passwd));
+        let ha2 = md5::compute(format!("{}:{}", request.method(), uri));
+
+        let mut header = match &challenge.qop {
+            Some(_) => {
+                let response = md5::compute(format!("{:x}:{}:{}:{}:auth:{:x}", ha1, challenge.nonce, nonce_count, cnonce, ha2));
+
+                format!("Digest username=\"{}\", realm=\"{}\", nonce=\"{}\", uri=\"{}\", algorithm=MD5, response=\"{:x}\", qop=auth, nc={}, cnonce=\"{}\"",
+                    user, challenge.realm, challenge.nonce, uri, response, nonce_count, cnonce)
+            }
+            None => {
+                let response = md5::compute(format!("{:x}:{}:{:x}", ha1, challenge.nonce, ha2));
+
+                format!("Digest username=\"{}\", realm=\"{}\", nonce=\"{}\", uri=\"{}\", algorithm=MD5, response=\"{:x}\"",
+                    user, challenge.realm, challenge.nonce, uri, response)
+            }
+        };
+
+        if let Some(opaque) = &challenge.opaque {
+            header.push_str(&format!(", opaque=\"{}\"", opaque));
+        }
+
+        if let Ok(header) = HeaderValue::from_str(&header) {
+            request.headers_mut().insert(AUTHORIZATION, header);
+        }
+    }
+}
+
+
+impl Cam {
+    pub(crate) fn security_url(&self) -> String {
+        format!("{}/ISAPI/Security/capabilities", self.host)
+    }
+
+    /// Scheme of the HTTP authentication used for the requests
+    pub fn auth_scheme(&self) -> AuthScheme {
+        self.auth_scheme
+    }
+
+    /// Add the authorization header: basic one for `Basic` and `Auto`, and the digest one once the camera sent a challenge.
+    /// Digest-only cameras get no header before the first challenge, so the password never goes out in clear text
+    fn authorize(&self, request: Request) -> Result<Request, reqwest::Error> {
+        let Some((user, passwd)) = &self.credentials else {
+            return Ok(request);
+        };
+
+        let mut request = match self.auth_scheme {
+            AuthScheme::Digest => request,
+            AuthScheme::Basic | AuthScheme::Auto => RequestBuilder::from_parts(self.client.clone(), request).basic_auth(user, Some(passwd)).build()?,
+        };
+
+        if self.auth_scheme != AuthScheme::Basic {
+            self.digest.authorize(&mut request, user, passwd);
+        }
+
+        Ok(request)
+    }
+
+    /// Send the request once, and once more if the camera answers 401 with a digest challenge
+    pub(crate) async fn execute(&self, request: Request) -> Result<Response, reqwest::Error> {
+        let retry = request.try_clone();
+        let res = self.client.execute(self.authorize(request)?).await?;
+
+        match retry {
+            Some(retry) if res.status() == StatusCode::UNAUTHORIZED && self.auth_scheme != AuthScheme::Basic && self.digest.update(res.headers()) => {
+                self.client.execute(self.authorize(retry)?).await
+            }
+            _ => Ok(res),
+        }
+    }
+}
@@ -0,0 +1,258 @@
+//! Typed values for each axis of the camera, so that pan, tilt and zoom can not be passed in the wrong order
+use std::time::{Duration, Instant};
+use crate::{error, Cam, CamResponse, TypeEvent};
+
+
+/// Horizontal movement, can vary from -100..=100
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Pan(i8);
+
+
+/// Vertical movement, can vary from -100..=100
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Tilt(i8);
+
+
+/// Zoom of the lens, can vary from -100..=100
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Zoom(i8);
+
+
+/// Axis of the camera, for the methods which are about an axis as a whole
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    Pan,
+    Tilt,
+    Zoom,
+}
+
+
+/// What `Cam::move_ptz_with` does when some of the axes lie outside -100..=100
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PartialMovePolicy {
+    /// Nothing is sent and `OutOfRangeUnitError` is returned, the same as `Cam::move_ptz` does
+    #[default]
+    Reject,
+    /// The axes outside the range are clamped to -100 or 100 and the movement is sent
+    Clamp,
+    /// The axes outside the range do not move, the others are sent
+    Skip,
+}
+
+
+/// What the `*_cam_f` movements do when the value lies outside -1.0..=1.0, see `Cam::set_float_policy`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FloatPolicy {
+    /// Nothing is sent and `OutOfRangeUnitError` is returned, the same as the integer movements do
+    #[default]
+    Reject,
+    /// The value is clamped to -1.0 or 1.0 and the movement is sent, NaN is still rejected
+    Clamp,
+    /// Nothing is sent and `Ok(None)` is returned
+    Ignore,
+}
+
+
+/// Result of `Cam::move_ptz_with`
+#[derive(Clone, Debug)]
+pub struct PartialMove {
+    pub response: CamResponse,
+    /// Axes which were clamped or skipped by the policy
+    pub adjusted: Vec<Axis>,
+}
+
+
+fn check_range(unit: i8, type_event: TypeEvent) -> Result<i8, error::OutOfRangeUnitError> {
+    if (-100..=100).contains(&unit) {
+        Ok(unit)
+    } else {
+        Err(error::OutOfRangeUnitError::new(unit, type_event))
+    }
+}
+
+
+impl Pan {
+    pub fn new(unit: i8) -> Result<Self, error::OutOfRangeUnitError> {
+        Ok(Self(check_range(unit, TypeEvent::Rotate)?))
+    }
+
+    pub fn get(&self) -> i8 {
+        self.0
+    }
+}
+
+
+impl Tilt {
+    pub fn new(unit: i8) -> Result<Self, error::OutOfRangeUnitError> {
+        Ok(Self(check_range(unit, TypeEvent::Tilt)?))
+    }
+
+    pub fn get(&self) -> i8 {
+        self.0
+    }
+}
+
+
+impl Zoom {
+    pub fn new(unit: i8) -> Result<Self, error::OutOfRangeUnitError> {
+        Ok(Self(check_range(unit, TypeEvent::Zoom)?))
+    }
+
+    pub fn get(&self) -> i8 {
+        self.0
+    }
+}
+
+
+/// Transform applied to pan, tilt and zoom values before they are sent to the camera
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ResponseCurve {
+    /// Values are sent as is
+    #[default]
+    Linear,
+    /// `100 * (unit / 100) ^ gamma` keeping the sign, with `gamma > 1` small values give finer control like on a joystick.
+    /// `gamma` must be finite and greater than 0
+    Exponential(f32),
+}
+
+
+impl ResponseCurve {
+    pub(crate) fn check(&self) -> Result<(), error::InvalidCurveError> {
+        match self {
+            Self::Exponential(gamma) if !gamma.is_finite() || *gamma <= 0.0 => Err(error::InvalidCurveError::new(*gamma)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Map `unit` from -100..=100 through the curve, 0 always stays 0
+    pub fn apply(&self, unit: i8) -> i8 {
+        match self {
+            Self::Linear => unit,
+            Self::Exponential(_) if unit == 0 => 0,
+            Self::Exponential(gamma) => {
+                let normalized = (unit as f32 / 100.0).clamp(-1.0, 1.0);
+
+                (normalized.signum() * normalized.abs().powf(*gamma) * 100.0).round() as i8
+            },
+        }
+    }
+}
+
+
+impl Cam {
+    fn axis_param(&self, axis: Axis) -> crate::CamParam {
+        let index = match axis {
+            Axis::Pan => 0,
+            Axis::Tilt => 1,
+            Axis::Zoom => 2,
+        };
+
+        self.axes()[index]
+    }
+
+    /// How long until the next momentary movement along `axis` passes the throttle, zero if it passes right now.
+    /// Useful to show a countdown instead of running into `QuickRequsetError`
+    pub fn time_until_ready(&self, axis: Axis) -> Duration {
+        let event = self.axis_param(axis);
+
+        if !self.throttle || event.is_init {
+            return Duration::ZERO;
+        }
+
+        let wait = Duration::from_millis(self.movement_speed.saturating_sub(50) as u64);
+
+        wait.saturating_sub(Instant::now().saturating_duration_since(event.last_trigger))
+    }
+
+    /// Whether a momentary movement along `axis` sent at `at` would be rejected with `QuickRequsetError`, judging by
+    /// the movements sent so far. It does not change anything, so a scheduler can use it to lay out the commands
+    pub fn would_throttle(&self, axis: Axis, at: Instant) -> bool {
+        self.throttles(&self.axis_param(axis), at)
+    }
+
+    /// Choose what `rotate_cam_f`, `tilt_cam_f` and `zoom_cam_f` do with values outside -1.0..=1.0,
+    /// `FloatPolicy::Reject` by default
+    pub async fn set_float_policy(&mut self, policy: FloatPolicy) {
+        self.float_policy = policy;
+    }
+
+    fn scale_float(&self, value: f32, type_event: TypeEvent) -> Result<Option<i8>, error::OutOfRangeUnitError> {
+        let unit = (value * 100.0).round();
+
+        if (-1.0..=1.0).contains(&value) {
+            return Ok(Some(unit as i8));
+        }
+
+        match self.float_policy {
+            FloatPolicy::Clamp if !value.is_nan() => Ok(Some(unit.clamp(-100.0, 100.0) as i8)),
+            FloatPolicy::Ignore => Ok(None),
+            _ => Err(error::OutOfRangeUnitError::new(unit as i8, type_event)),
+        }
+    }
+
+    /// Same as `rotate_cam` for an analog axis from -1.0..=1.0. The value is multiplied by 100 and rounded to the nearest
+    /// unit, halves away from zero, so 0.005 is already 1. Values outside the range are handled by `set_float_policy`
+    pub async fn rotate_cam_f(&mut self, v: f32) -> Result<Option<CamResponse>, Box<dyn std::error::Error>> {
+        match self.scale_float(v, TypeEvent::Rotate)? {
+            Some(unit) => Ok(Some(self.rotate_cam(unit).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Same as `tilt_cam` for an analog axis from -1.0..=1.0, see `rotate_cam_f`
+    pub async fn tilt_cam_f(&mut self, v: f32) -> Result<Option<CamResponse>, Box<dyn std::error::Error>> {
+        match self.scale_float(v, TypeEvent::Tilt)? {
+            Some(unit) => Ok(Some(self.tilt_cam(unit).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Same as `zoom_cam` for an analog axis from -1.0..=1.0, see `rotate_cam_f`
+    pub async fn zoom_cam_f(&mut self, v: f32) -> Result<Option<CamResponse>, Box<dyn std::error::Error>> {
+        match self.scale_float(v, TypeEvent::Zoom)? {
+            Some(unit) => Ok(Some(self.zoom_cam(unit).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Continuous velocity which moves the camera as far during `window_ms` as a momentary movement of `unit` lasting
+    /// `duration_ms` does. It assumes that the speed of the camera grows linearly with the value and that both endpoints
+    /// use the same -100..=100 scale, so with pulses sent every `duration_ms` the velocity equals `unit`
+    pub fn momentary_to_continuous(unit: i8, duration_ms: usize, window_ms: usize) -> i8 {
+        if window_ms == 0 {
+            return unit.signum() * 100;
+        }
+
+        (unit as f64 * duration_ms as f64 / window_ms as f64).round().clamp(-100.0, 100.0) as i8
+    }
+
+    /// Momentary value lasting `duration_ms` which moves the camera as far as the continuous `velocity` does during
+    /// `window_ms`, the inverse of `momentary_to_continuous` with the same assumptions
+    pub fn continuous_to_momentary(velocity: i8, window_ms: usize, duration_ms: usize) -> i8 {
+        Self::momentary_to_continuous(velocity, window_ms, duration_ms)
+    }
+
+    /// Same as `move_ptz`, but the axes outside -100..=100 are handled according to `policy` instead of always
+    /// rejecting the whole movement, which is handy for joystick control. The adjusted axes are listed in the result
+    pub async fn move_ptz_with(&mut self, pan: i8, tilt: i8, zoom: i8, policy: PartialMovePolicy) -> Result<PartialMove, Box<dyn std::error::Error>> {
+        let mut adjusted = Vec::new();
+        let mut units = [(pan, Axis::Pan), (tilt, Axis::Tilt), (zoom, Axis::Zoom)];
+
+        if policy != PartialMovePolicy::Reject {
+            for (unit, axis) in units.iter_mut() {
+                if !(-100..=100).contains(unit) {
+                    *unit = match policy {
+                        PartialMovePolicy::Clamp => (*unit).clamp(-100, 100),
+                        _ => 0,
+                    };
+                    adjusted.push(*axis);
+                }
+            }
+        }
+
+        Ok(PartialMove {
+            response: self.move_ptz(units[0].0, units[1].0, units[2].0).await?,
+            adjusted,
+        })
+    }
+}
@@ -0,0 +1,34 @@
+//! Positions remembered by the library instead of the camera, so their number is not limited by the preset slots
+use std::collections::HashMap;
+use crate::{error, status::PtzStatus, Cam, CamResponse};
+
+
+impl Cam {
+    /// Remember the current position of the camera as the bookmark `name`, an existing bookmark is overwritten.
+    /// Bookmarks live only in this `Cam`, use `bookmarks` and `set_bookmarks` to keep them between runs
+    pub async fn bookmark(&mut self, name: &str) -> Result<PtzStatus, Box<dyn std::error::Error>> {
+        let position = self.get_status().await?;
+        self.bookmarks.insert(name.to_string(), position);
+
+        Ok(position)
+    }
+
+    /// Move the camera to the bookmark `name` with an absolute movement
+    pub async fn goto_bookmark(&mut self, name: &str) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let Some(position) = self.bookmarks.get(name).copied() else {
+            return Err(Box::new(error::BookmarkNotFoundError::new(name.to_string())));
+        };
+
+        self.move_absolute(position.azimuth, position.elevation, position.absolute_zoom).await
+    }
+
+    /// All bookmarks by their names, with the `serde` feature they can be serialized as they are
+    pub fn bookmarks(&self) -> &HashMap<String, PtzStatus> {
+        &self.bookmarks
+    }
+
+    /// Replace all bookmarks, e.g. with the ones saved by the previous run
+    pub fn set_bookmarks(&mut self, bookmarks: HashMap<String, PtzStatus>) {
+        self.bookmarks = bookmarks;
+    }
+}
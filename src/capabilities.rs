@@ -0,0 +1,106 @@
+//! Structured view of what the PTZ of the camera is able to do
+use crate::{xml, Cam};
+
+
+/// Range of values accepted by the camera, both ends are included
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Range {
+    pub min: i32,
+    pub max: i32,
+}
+
+
+impl Range {
+    pub fn contains(&self, value: i32) -> bool {
+        (self.min..=self.max).contains(&value)
+    }
+
+    pub(crate) fn parse(body: &str) -> Option<Self> {
+        Some(Self {
+            min: xml::parse_tag(body, "Min")?,
+            max: xml::parse_tag(body, "Max")?,
+        })
+    }
+}
+
+
+/// Capabilities of the PTZ channel, the fields are `None` if the camera does not report them
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Range of the absolute azimuth in tenths of a degree
+    pub absolute_pan: Option<Range>,
+    /// Range of the absolute elevation in tenths of a degree
+    pub absolute_tilt: Option<Range>,
+    /// Range of the absolute zoom multiplied by ten
+    pub absolute_zoom: Option<Range>,
+    /// Range of the continuous pan speed, usually -100..=100
+    pub continuous_pan: Option<Range>,
+    /// Range of the continuous tilt speed, usually -100..=100
+    pub continuous_tilt: Option<Range>,
+    /// Range of the continuous zoom speed, usually -100..=100
+    pub continuous_zoom: Option<Range>,
+    /// Range of the momentary pan speed, usually -100..=100
+    pub momentary_pan: Option<Range>,
+    /// Range of the momentary tilt speed, usually -100..=100
+    pub momentary_tilt: Option<Range>,
+    /// Range of the momentary zoom speed, usually -100..=100
+    pub momentary_zoom: Option<Range>,
+    /// Range of the PTZ smoothing level of the camera
+    pub smoothing: Option<Range>,
+    /// Range of the momentary movement duration in milliseconds
+    pub momentary_duration: Option<Range>,
+}
+
+
+impl Capabilities {
+    pub(crate) fn parse(body: &str) -> Self {
+        let range = |space: &str, axis: &str| xml::tag_value(body, space).and_then(|space| xml::tag_value(space, axis)).and_then(Range::parse);
+
+        Self {
+            absolute_pan: range("AbsolutePanTiltPositionSpace", "XRange"),
+            absolute_tilt: range("AbsolutePanTiltPositionSpace", "YRange"),
+            absolute_zoom: range("AbsoluteZoomPositionSpace", "ZRange"),
+            continuous_pan: range("ContinuousPanTiltSpace", "XRange"),
+            continuous_tilt: range("ContinuousPanTiltSpace", "YRange"),
+            continuous_zoom: range("ContinuousZoomSpace", "ZRange"),
+            momentary_pan: range("MomentaryPanTiltSpace", "XRange"),
+            momentary_tilt: range("MomentaryPanTiltSpace", "YRange"),
+            momentary_zoom: range("MomentaryZoomSpace", "ZRange"),
+            smoothing: xml::tag_value(body, "SmoothingLevel").and_then(Range::parse),
+            momentary_duration: xml::tag_value(body, "MomentaryDuration").and_then(Range::parse),
+        }
+    }
+}
+
+
+impl Cam {
+    /// Get the unparsed capabilities XML of the PTZ channel, useful to look at what an unknown model reports
+    pub async fn capabilities_raw(&self) -> Result<String, Box<dyn std::error::Error>> {
+        self.capabilities().await
+    }
+
+    /// Get the parsed capabilities of the PTZ channel
+    pub async fn get_capabilities(&mut self) -> Result<Capabilities, Box<dyn std::error::Error>> {
+        Ok(Capabilities::parse(&self.capabilities().await?))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn parses_the_ranges_of_a_real_camera() {
+        let capabilities = Capabilities::parse(include_str!("../tests/fixtures/ptz_capabilities.xml"));
+
+        assert_eq!(capabilities.absolute_pan, Some(Range { min: 0, max: 3600 }));
+        assert_eq!(capabilities.absolute_tilt, Some(Range { min: -900, max: 2700 }));
+        assert_eq!(capabilities.absolute_zoom, Some(Range { min: 10, max: 250 }));
+        assert_eq!(capabilities.continuous_pan, Some(Range { min: -100, max: 100 }));
+        assert_eq!(capabilities.momentary_zoom, Some(Range { min: -100, max: 100 }));
+        assert_eq!(capabilities.momentary_duration, Some(Range { min: 100, max: 5000 }));
+        assert_eq!(capabilities.smoothing, None);
+    }
+}
@@ -0,0 +1,106 @@
+//! PTZ channels of the device, NVRs may have several of them
+use crate::{error, xml, Cam};
+
+
+/// Short description of a PTZ channel of the device
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PtzChannel {
+    pub id: u32,
+    pub enabled: bool,
+    /// Video input to which the channel is bound
+    pub video_input_id: Option<u32>,
+    /// Protocol used to control the PTZ, for example `HIKVISION` or `PELCO-D`
+    pub control_protocol: Option<String>,
+}
+
+
+impl PtzChannel {
+    pub(crate) fn parse_list(body: &str) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut channels = Vec::new();
+
+        for channel in xml::tag_values(body, "PTZChannel") {
+            channels.push(Self {
+                id: xml::parse_tag(channel, "id").ok_or(error::ParseResponseError::new("id"))?,
+                enabled: xml::tag_value(channel, "enabled") != Some("false"),
+                video_input_id: xml::parse_tag(channel, "videoInputID"),
+                control_protocol: xml::tag_value(channel, "controlProtocol").map(String::from),
+            });
+        }
+
+        Ok(channels)
+    }
+}
+
+
+impl Cam {
+    /// Get all PTZ channels of the device, a single camera usually has only one. Use the ids with `CamBuilder::channel`
+    pub async fn list_ptz_channels(&mut self) -> Result<Vec<PtzChannel>, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.ptz_channels_url(), "channels").await?;
+
+        PtzChannel::parse_list(&body)
+    }
+
+    /// Whether PTZ control is enabled for the channel of this object, so that the setup can tell this misconfiguration
+    /// from others instead of guessing from a failed movement. A device without this PTZ channel gives `false`.
+    /// Only errors of the transport and the authentication are returned
+    pub async fn is_ptz_enabled(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+        match self.get_xml(format!("{}/{}", self.ptz_channels_url(), self.channel), "PTZ channel").await {
+            Ok(body) => Ok(xml::tag_value(&body, "enabled") != Some("false")),
+            Err(err) if err.is::<error::UnsupportedFeatureError>() || err.is::<error::BadResponseError>() => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Connect to the device and get a camera object for every channel which supports PTZ, e.g. for all cameras of an NVR.
+    /// The objects share one HTTP client. A channel whose capabilities can not be read is skipped, the others are returned
+    pub async fn all_channels<S>(addr: S, port: usize, user_passwd: Option<(S, S)>, movment_speed_ms: usize) -> Result<Vec<Cam>, Box<dyn std::error::Error>> where S: Into<String> {
+        let mut cam = Cam::new(addr, port, user_passwd, movment_speed_ms).await?;
+        let mut cams = Vec::new();
+
+        for channel in cam.list_ptz_channels().await? {
+            if !channel.enabled {
+                continue;
+            }
+
+            let mut channel_cam = cam.for_channel(channel.id);
+            match channel_cam.capabilities().await {
+                Ok(capabilities) => {
+                    channel_cam.features = crate::features::Features::parse(&capabilities);
+                    cams.push(channel_cam);
+                }
+                Err(_err) => {
+                    #[cfg(feature = "tracing")]
+                    if !_err.is::<error::UnsupportedFeatureError>() {
+                        tracing::warn!(channel = channel.id, error = %_err, "reading the capabilities of the channel failed, skipping it");
+                    }
+                }
+            }
+        }
+
+        Ok(cams)
+    }
+
+    fn for_channel(&self, id: u32) -> Cam {
+        let mut cam = self.clone();
+        cam.channel = id;
+        // `reconnect` builds from the configuration, which has to point at this channel as well
+        cam.config = cam.config.clone().channel(id);
+        cam.capabilities_available = true;
+        cam.recording = None;
+        cam.stats = Default::default();
+        cam.axes = Default::default();
+        #[cfg(feature = "tokio")]
+        {
+            cam.idle_watchdog = None;
+            cam.continuous_velocity = Default::default();
+            cam.in_flight = Default::default();
+        }
+
+        cam
+    }
+
+    /// PTZ channel which this object controls
+    pub fn channel(&self) -> u32 {
+        self.channel
+    }
+}
@@ -0,0 +1,113 @@
+//! Information about the device and quirks of particular firmware
+//!
+//! The library does not switch any setting by the model or the firmware version, it keeps the defaults until they are
+//! changed on `CamBuilder`. No list of affected models is kept, because the quirks vary between firmware builds of the same
+//! model. Use `Cam::model` and `DeviceInfo::firmware_version` to key your own table, the known quirks and their settings are:
+//!
+//! | Symptom | Setting |
+//! |---|---|
+//! | The camera moves without stopping or ignores the duration of momentary movements | `CamBuilder::body_dialect(BodyDialect::V10)` |
+//! | Momentary movements last 10 times longer than expected | `CamBuilder::duration_unit` |
+//! | The momentary endpoint answers 404 | `CamBuilder::momentary_path(MomentaryPath::Lowercase)`, also found by itself on the first 404 |
+//! | The bodies are rejected without the `xmlns` of ISAPI 2.0 | Not supported yet, the bodies are sent without a namespace |
+use std::time::Duration;
+use crate::{error, xml, Cam, CamResponse};
+
+
+/// Information from `/ISAPI/System/deviceInfo`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceInfo {
+    pub device_name: Option<String>,
+    pub model: String,
+    pub serial_number: Option<String>,
+    /// Firmware version like `V5.5.0`
+    pub firmware_version: Option<String>,
+    pub device_type: Option<String>,
+}
+
+
+impl DeviceInfo {
+    pub(crate) fn parse(body: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self {
+            device_name: xml::tag_value(body, "deviceName").map(String::from),
+            model: xml::tag_value(body, "model").ok_or(error::ParseResponseError::new("model"))?.to_string(),
+            serial_number: xml::tag_value(body, "serialNumber").map(String::from),
+            firmware_version: xml::tag_value(body, "firmwareVersion").map(String::from),
+            device_type: xml::tag_value(body, "deviceType").map(String::from),
+        })
+    }
+
+    /// Major number of the firmware version, e.g. 5 for `V5.5.0`
+    pub fn firmware_major(&self) -> Option<u32> {
+        self.firmware_version.as_deref()?.trim_start_matches(['V', 'v']).split('.').next()?.parse().ok()
+    }
+}
+
+
+/// Health of the device from `/ISAPI/System/status`, the fields are `None` if the camera does not report them
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Diagnostics {
+    pub uptime: Option<Duration>,
+    /// Internal temperature in degrees Celsius
+    pub temperature: Option<f32>,
+    /// CPU load in percent
+    pub cpu_utilization: Option<u8>,
+    /// Used memory in percent
+    pub memory_usage: Option<f32>,
+}
+
+
+impl Diagnostics {
+    pub(crate) fn parse(body: &str) -> Self {
+        Self {
+            uptime: xml::parse_tag(body, "deviceUpTime").map(Duration::from_secs),
+            temperature: xml::parse_tag(body, "temperature").or_else(|| xml::parse_tag(body, "deviceTemperature")),
+            cpu_utilization: xml::parse_tag(body, "cpuUtilization"),
+            memory_usage: xml::parse_tag(body, "memoryUsage"),
+        }
+    }
+}
+
+
+impl Cam {
+    /// Get the information about the device
+    pub async fn get_device_info(&mut self) -> Result<DeviceInfo, Box<dyn std::error::Error>> {
+        let body = self.get_xml(format!("{}/ISAPI/System/deviceInfo", self.host), "device info").await?;
+
+        DeviceInfo::parse(&body)
+    }
+
+    /// Get the name under which the device is shown, e.g. in the web interface and in NVRs
+    pub async fn device_name(&mut self) -> Result<String, Box<dyn std::error::Error>> {
+        let body = self.get_xml(format!("{}/ISAPI/System/deviceInfo", self.host), "device info").await?;
+
+        Ok(xml::unescape(xml::tag_value(&body, "deviceName").ok_or(error::ParseResponseError::new("deviceName"))?))
+    }
+
+    /// Change the name under which the device is shown, other device info is kept as it is
+    pub async fn set_device_name(&mut self, name: &str) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let url = format!("{}/ISAPI/System/deviceInfo", self.host);
+        let config = self.get_xml(url.clone(), "device info").await?;
+        let config = xml::replace_tag(&config, "deviceName", &xml::escape(name)).ok_or(error::ParseResponseError::new("deviceName"))?;
+
+        let res = self.send(self.client.put(url).body(config)).await?;
+
+        Self::check_supported(res, "device info").await
+    }
+
+    /// Get the uptime, temperature and load of the device. Diagnostics differ a lot between models, so everything the
+    /// camera does not report is `None`, and if it has no status endpoint at all every field is `None`
+    pub async fn diagnostics(&mut self) -> Result<Diagnostics, Box<dyn std::error::Error>> {
+        match self.get_xml(format!("{}/ISAPI/System/status", self.host), "diagnostics").await {
+            Ok(body) => Ok(Diagnostics::parse(&body)),
+            Err(err) if err.is::<error::UnsupportedFeatureError>() => Ok(Diagnostics::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Model of the camera, it is read by `Cam::new` and `CamBuilder::build`, so it is `None` for unchecked
+    /// constructors or if the camera did not tell it
+    pub fn model(&self) -> Option<&str> {
+        self.model.as_deref()
+    }
+}
@@ -1,6 +1,6 @@
 /// `ErrorAuthorize`, usually occurs when the login or password is incorrect or due to the lack of certain access rights to the camera
-use std::fmt;
-use crate::TypeEvent;
+use std::{fmt, time};
+use crate::{capabilities::Range, TypeEvent};
 
 
 pub struct ErrorAuthorize;
@@ -27,16 +27,28 @@ impl fmt::Debug for ErrorAuthorize {
 pub struct QuickRequsetError {
     timeout: usize,
     event: TypeEvent,
+    next_available: time::Instant,
 }
 
 
 impl QuickRequsetError {
-    pub(crate) fn new(_timeout: usize, _event: TypeEvent) -> Self {
+    pub(crate) fn new(_timeout: usize, _event: TypeEvent, _next_available: time::Instant) -> Self {
         Self {
-            timeout: _timeout + 50,
+            timeout: _timeout.saturating_add(50),
             event: _event,
+            next_available: _next_available,
         }
     }
+
+    /// Moment after which the action should be repeated, later than strictly needed if `Cam::set_throttle_backoff` is enabled
+    pub fn next_available(&self) -> time::Instant {
+        self.next_available
+    }
+
+    /// How long to wait from now until `next_available`
+    pub fn retry_after(&self) -> time::Duration {
+        self.next_available.saturating_duration_since(time::Instant::now())
+    }
 }
 
 
@@ -88,3 +100,841 @@ impl fmt::Debug for OutOfRangeUnitError {
         write!(f, "The unit of measurment for the <{}> event does ot lie in the range -100..=100, its value {}", self.event.get_str(), self.data)
     }
 }
+
+
+/// `UnsupportedFeatureError` occurs when the camera reports that it does not support the requested feature
+pub struct UnsupportedFeatureError {
+    feature: &'static str,
+}
+
+
+impl UnsupportedFeatureError {
+    pub(crate) fn new(_feature: &'static str) -> Self {
+        Self {
+            feature: _feature,
+        }
+    }
+}
+
+
+impl std::error::Error for UnsupportedFeatureError {}
+
+
+impl fmt::Display for UnsupportedFeatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The camera does not support the <{}> feature", self.feature)
+    }
+}
+
+
+impl fmt::Debug for UnsupportedFeatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The camera does not support the <{}> feature", self.feature)
+    }
+}
+
+
+/// `BadResponseError` occurs when the camera rejected the request, the status and the answer of the camera are kept inside
+/// together with the body of the request
+pub struct BadResponseError {
+    status: reqwest::StatusCode,
+    body: String,
+    request: Option<String>,
+}
+
+
+impl BadResponseError {
+    pub(crate) fn new(_status: reqwest::StatusCode, _body: String, _request: Option<String>) -> Self {
+        Self {
+            status: _status,
+            body: _body,
+            request: _request,
+        }
+    }
+
+    pub fn status(&self) -> reqwest::StatusCode {
+        self.status
+    }
+
+    /// Full answer of the camera, usually with `<ResponseStatus>`
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Body of the rejected request exactly as it was sent, `None` for the requests without a body
+    pub fn request(&self) -> Option<&str> {
+        self.request.as_deref()
+    }
+}
+
+
+impl std::error::Error for BadResponseError {}
+
+
+impl fmt::Display for BadResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.request {
+            Some(request) => write!(f, "The camera rejected the request {} with status {}: {}", request, self.status, self.body),
+            None => write!(f, "The camera rejected the request with status {}: {}", self.status, self.body),
+        }
+    }
+}
+
+
+impl fmt::Debug for BadResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.request {
+            Some(request) => write!(f, "The camera rejected the request {} with status {}: {}", request, self.status, self.body),
+            None => write!(f, "The camera rejected the request with status {}: {}", self.status, self.body),
+        }
+    }
+}
+
+
+/// `ParseResponseError` occurs when the answer of the camera does not contain the expected field or it has an unexpected format
+pub struct ParseResponseError {
+    field: &'static str,
+}
+
+
+impl ParseResponseError {
+    pub(crate) fn new(_field: &'static str) -> Self {
+        Self {
+            field: _field,
+        }
+    }
+}
+
+
+impl std::error::Error for ParseResponseError {}
+
+
+impl fmt::Display for ParseResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to read the <{}> field from the answer of the camera", self.field)
+    }
+}
+
+
+impl fmt::Debug for ParseResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to read the <{}> field from the answer of the camera", self.field)
+    }
+}
+
+
+/// `PresetNotFoundError` occurs when the referenced preset is not saved on the camera
+pub struct PresetNotFoundError {
+    id: u16,
+}
+
+
+impl PresetNotFoundError {
+    pub(crate) fn new(_id: u16) -> Self {
+        Self {
+            id: _id,
+        }
+    }
+}
+
+
+impl std::error::Error for PresetNotFoundError {}
+
+
+impl fmt::Display for PresetNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The preset with id {} is not saved on the camera", self.id)
+    }
+}
+
+
+impl fmt::Debug for PresetNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The preset with id {} is not saved on the camera", self.id)
+    }
+}
+
+
+/// `PatrolTooLongError` occurs when a patrol has more points than the camera can hold
+pub struct PatrolTooLongError {
+    len: usize,
+    max: usize,
+}
+
+
+impl PatrolTooLongError {
+    pub(crate) fn new(_len: usize, _max: usize) -> Self {
+        Self {
+            len: _len,
+            max: _max,
+        }
+    }
+}
+
+
+impl std::error::Error for PatrolTooLongError {}
+
+
+impl fmt::Display for PatrolTooLongError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The patrol has {} points, but the camera supports at most {}", self.len, self.max)
+    }
+}
+
+
+impl fmt::Debug for PatrolTooLongError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The patrol has {} points, but the camera supports at most {}", self.len, self.max)
+    }
+}
+
+
+/// `PatrolNotFoundError` occurs when the referenced patrol is not defined on the camera
+pub struct PatrolNotFoundError {
+    id: u8,
+}
+
+
+impl PatrolNotFoundError {
+    pub(crate) fn new(_id: u8) -> Self {
+        Self {
+            id: _id,
+        }
+    }
+}
+
+
+impl std::error::Error for PatrolNotFoundError {}
+
+
+impl fmt::Display for PatrolNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The patrol with id {} is not defined on the camera", self.id)
+    }
+}
+
+
+impl fmt::Debug for PatrolNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The patrol with id {} is not defined on the camera", self.id)
+    }
+}
+
+
+/// `CameraBusyError` occurs when the camera is controlled by another client right now, try again a little later
+pub struct CameraBusyError;
+
+
+impl std::error::Error for CameraBusyError {}
+
+
+impl fmt::Display for CameraBusyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The camera is busy, probably it is controlled by another client right now. Try again later")
+    }
+}
+
+
+impl fmt::Debug for CameraBusyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The camera is busy, probably it is controlled by another client right now. Try again later")
+    }
+}
+
+
+/// `InvalidUrlError` occurs when the URL of the camera can not be used to connect to it
+pub struct InvalidUrlError {
+    url: String,
+    reason: &'static str,
+}
+
+
+impl InvalidUrlError {
+    pub(crate) fn new(_url: String, _reason: &'static str) -> Self {
+        Self {
+            url: _url,
+            reason: _reason,
+        }
+    }
+}
+
+
+impl std::error::Error for InvalidUrlError {}
+
+
+impl fmt::Display for InvalidUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid camera URL <{}>: {}", self.url, self.reason)
+    }
+}
+
+
+impl fmt::Debug for InvalidUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid camera URL <{}>: {}", self.url, self.reason)
+    }
+}
+
+
+/// `ConnectError` occurs when connecting to the camera in `Cam::new` or `CamBuilder::build` failed, the variant tells why
+pub enum ConnectError {
+    /// The camera could not be reached, check the address, the port and the network
+    Unreachable(reqwest::Error),
+    /// The camera did not answer in time
+    Timeout(reqwest::Error),
+    /// The camera rejected the login or password
+    Unauthorized,
+    /// The camera answered, but not in the way it was expected to
+    ProtocolError(reqwest::Error),
+}
+
+
+impl From<reqwest::Error> for ConnectError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::Timeout(err)
+        } else if err.is_connect() {
+            Self::Unreachable(err)
+        } else {
+            Self::ProtocolError(err)
+        }
+    }
+}
+
+
+impl std::error::Error for ConnectError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Unreachable(err) | Self::Timeout(err) | Self::ProtocolError(err) => Some(err),
+            Self::Unauthorized => None,
+        }
+    }
+}
+
+
+impl fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unreachable(err) => write!(f, "The camera is unreachable, check the address, the port and the network: {}", err),
+            Self::Timeout(err) => write!(f, "The camera did not answer in time: {}", err),
+            Self::Unauthorized => write!(f, "{}", ErrorAuthorize),
+            Self::ProtocolError(err) => write!(f, "The camera answered in an unexpected way: {}", err),
+        }
+    }
+}
+
+
+impl fmt::Debug for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+
+/// `OutOfRangeDegreesError` occurs when the camera can not physically turn to the requested angle
+pub struct OutOfRangeDegreesError {
+    axis: &'static str,
+    degrees: f32,
+    range: Range,
+}
+
+
+impl OutOfRangeDegreesError {
+    pub(crate) fn new(_axis: &'static str, _degrees: f32, _range: Range) -> Self {
+        Self {
+            axis: _axis,
+            degrees: _degrees,
+            range: _range,
+        }
+    }
+}
+
+
+impl std::error::Error for OutOfRangeDegreesError {}
+
+
+impl fmt::Display for OutOfRangeDegreesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> of {} degrees does not lie in the range {}..={} degrees of the camera", self.axis, self.degrees, self.range.min as f32 / 10.0, self.range.max as f32 / 10.0)
+    }
+}
+
+
+impl fmt::Debug for OutOfRangeDegreesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> of {} degrees does not lie in the range {}..={} degrees of the camera", self.axis, self.degrees, self.range.min as f32 / 10.0, self.range.max as f32 / 10.0)
+    }
+}
+
+
+/// `InvalidScheduleError` occurs when a block of the schedule has an impossible time or ends before it starts
+pub struct InvalidScheduleError {
+    start: (u8, u8),
+    end: (u8, u8),
+}
+
+
+impl InvalidScheduleError {
+    pub(crate) fn new(_start: (u8, u8), _end: (u8, u8)) -> Self {
+        Self {
+            start: _start,
+            end: _end,
+        }
+    }
+}
+
+
+impl std::error::Error for InvalidScheduleError {}
+
+
+impl fmt::Display for InvalidScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The schedule block {:02}:{:02}-{:02}:{:02} is invalid, the times must lie in 00:00..=24:00 and the start must be before the end", self.start.0, self.start.1, self.end.0, self.end.1)
+    }
+}
+
+
+impl fmt::Debug for InvalidScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The schedule block {:02}:{:02}-{:02}:{:02} is invalid, the times must lie in 00:00..=24:00 and the start must be before the end", self.start.0, self.start.1, self.end.0, self.end.1)
+    }
+}
+
+
+/// `OutOfRangeValueError` occurs when a setting does not lie in the range which the camera accepts for it
+pub struct OutOfRangeValueError {
+    field: &'static str,
+    value: i32,
+    range: Range,
+}
+
+
+impl OutOfRangeValueError {
+    pub(crate) fn new(_field: &'static str, _value: i32, _range: Range) -> Self {
+        Self {
+            field: _field,
+            value: _value,
+            range: _range,
+        }
+    }
+}
+
+
+impl std::error::Error for OutOfRangeValueError {}
+
+
+impl fmt::Display for OutOfRangeValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> value {} does not lie in the range {}..={} of the camera", self.field, self.value, self.range.min, self.range.max)
+    }
+}
+
+
+impl fmt::Debug for OutOfRangeValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> value {} does not lie in the range {}..={} of the camera", self.field, self.value, self.range.min, self.range.max)
+    }
+}
+
+
+/// `InvalidDateFormatError` occurs when the date format of the overlay is not one of the formats the camera accepts
+pub struct InvalidDateFormatError {
+    format: String,
+}
+
+
+impl InvalidDateFormatError {
+    pub(crate) fn new(_format: String) -> Self {
+        Self {
+            format: _format,
+        }
+    }
+}
+
+
+impl std::error::Error for InvalidDateFormatError {}
+
+
+impl fmt::Display for InvalidDateFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The date format <{}> is not accepted by the camera, use one of {:?}", self.format, crate::osd::DATE_FORMATS)
+    }
+}
+
+
+impl fmt::Debug for InvalidDateFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The date format <{}> is not accepted by the camera, use one of {:?}", self.format, crate::osd::DATE_FORMATS)
+    }
+}
+
+
+/// `DuplicateCommandError` occurs when the same movement is sent again inside the window of `Cam::set_idempotency_window`
+pub struct DuplicateCommandError {
+    data: i8,
+    event: TypeEvent,
+}
+
+
+impl DuplicateCommandError {
+    pub(crate) fn new(_data: i8, _event: TypeEvent) -> Self {
+        Self {
+            data: _data,
+            event: _event,
+        }
+    }
+}
+
+
+impl std::error::Error for DuplicateCommandError {}
+
+
+impl fmt::Display for DuplicateCommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> action with the value {} was already sent a moment ago, call `Cam::force_next` to send it again", self.event.get_str(), self.data)
+    }
+}
+
+
+impl fmt::Debug for DuplicateCommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> action with the value {} was already sent a moment ago, call `Cam::force_next` to send it again", self.event.get_str(), self.data)
+    }
+}
+
+
+/// `PresetLimitExceededError` occurs when the preset id lies beyond the number of presets the camera supports
+pub struct PresetLimitExceededError {
+    id: u16,
+    max: u16,
+}
+
+
+impl PresetLimitExceededError {
+    pub(crate) fn new(_id: u16, _max: u16) -> Self {
+        Self {
+            id: _id,
+            max: _max,
+        }
+    }
+}
+
+
+impl std::error::Error for PresetLimitExceededError {}
+
+
+impl fmt::Display for PresetLimitExceededError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The preset id {} does not lie in the range 1..={} of the presets supported by the camera", self.id, self.max)
+    }
+}
+
+
+impl fmt::Debug for PresetLimitExceededError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The preset id {} does not lie in the range 1..={} of the presets supported by the camera", self.id, self.max)
+    }
+}
+
+
+/// `NotAllowedValueError` occurs when a setting is not one of the values which the camera offers for it
+pub struct NotAllowedValueError {
+    field: &'static str,
+    value: i32,
+    allowed: Vec<i32>,
+}
+
+
+impl NotAllowedValueError {
+    pub(crate) fn new(_field: &'static str, _value: i32, _allowed: Vec<i32>) -> Self {
+        Self {
+            field: _field,
+            value: _value,
+            allowed: _allowed,
+        }
+    }
+}
+
+
+impl std::error::Error for NotAllowedValueError {}
+
+
+impl fmt::Display for NotAllowedValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> value {} is not allowed by the camera, the allowed values are {:?}", self.field, self.value, self.allowed)
+    }
+}
+
+
+impl fmt::Debug for NotAllowedValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> value {} is not allowed by the camera, the allowed values are {:?}", self.field, self.value, self.allowed)
+    }
+}
+
+
+/// `InvalidSpeedError` occurs when the movement speed is shorter than `MIN_MOVEMENT_SPEED`
+pub struct InvalidSpeedError {
+    ms: usize,
+}
+
+
+impl InvalidSpeedError {
+    pub(crate) fn new(_ms: usize) -> Self {
+        Self {
+            ms: _ms,
+        }
+    }
+}
+
+
+impl std::error::Error for InvalidSpeedError {}
+
+
+impl fmt::Display for InvalidSpeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The movement speed {}ms is too short, it must be at least {}ms", self.ms, crate::MIN_MOVEMENT_SPEED)
+    }
+}
+
+
+impl fmt::Debug for InvalidSpeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The movement speed {}ms is too short, it must be at least {}ms", self.ms, crate::MIN_MOVEMENT_SPEED)
+    }
+}
+
+
+/// `OutputNotFoundError` occurs when the device does not have the referenced alarm output
+pub struct OutputNotFoundError {
+    id: u8,
+}
+
+
+impl OutputNotFoundError {
+    pub(crate) fn new(_id: u8) -> Self {
+        Self {
+            id: _id,
+        }
+    }
+}
+
+
+impl std::error::Error for OutputNotFoundError {}
+
+
+impl fmt::Display for OutputNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The device does not have the alarm output with id {}", self.id)
+    }
+}
+
+
+impl fmt::Debug for OutputNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The device does not have the alarm output with id {}", self.id)
+    }
+}
+
+
+/// `GridSizeError` occurs when the motion grid does not have the number of rows and columns the camera reports
+pub struct GridSizeError {
+    expected: (usize, usize),
+    actual: (usize, usize),
+}
+
+
+impl GridSizeError {
+    pub(crate) fn new(_expected: (usize, usize), _actual: (usize, usize)) -> Self {
+        Self {
+            expected: _expected,
+            actual: _actual,
+        }
+    }
+}
+
+
+impl std::error::Error for GridSizeError {}
+
+
+impl fmt::Display for GridSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The motion grid has {}x{} cells, but the camera has {}x{} (rows x columns)", self.actual.0, self.actual.1, self.expected.0, self.expected.1)
+    }
+}
+
+
+impl fmt::Debug for GridSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The motion grid has {}x{} cells, but the camera has {}x{} (rows x columns)", self.actual.0, self.actual.1, self.expected.0, self.expected.1)
+    }
+}
+
+
+/// `BookmarkNotFoundError` occurs when there is no bookmark with the given name
+pub struct BookmarkNotFoundError {
+    name: String,
+}
+
+
+impl BookmarkNotFoundError {
+    pub(crate) fn new(_name: String) -> Self {
+        Self {
+            name: _name,
+        }
+    }
+}
+
+
+impl std::error::Error for BookmarkNotFoundError {}
+
+
+impl fmt::Display for BookmarkNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "There is no bookmark named <{}>", self.name)
+    }
+}
+
+
+impl fmt::Debug for BookmarkNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "There is no bookmark named <{}>", self.name)
+    }
+}
+
+
+/// `StopFailedError` occurs when the camera did not accept the stop at the end of `Cam::move_for`, it may still be moving
+pub struct StopFailedError {
+    attempts: u8,
+    reason: String,
+}
+
+
+#[cfg(feature = "tokio")]
+impl StopFailedError {
+    pub(crate) fn new(_attempts: u8, _reason: String) -> Self {
+        Self {
+            attempts: _attempts,
+            reason: _reason,
+        }
+    }
+}
+
+
+impl std::error::Error for StopFailedError {}
+
+
+impl fmt::Display for StopFailedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The camera MAY STILL BE MOVING: the stop failed {} times, the last error is <{}>", self.attempts, self.reason)
+    }
+}
+
+
+impl fmt::Debug for StopFailedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The camera MAY STILL BE MOVING: the stop failed {} times, the last error is <{}>", self.attempts, self.reason)
+    }
+}
+
+
+/// `InvalidCurveError` occurs when the exponent of `ResponseCurve::Exponential` is not finite or not greater than 0
+pub struct InvalidCurveError {
+    gamma: f32,
+}
+
+
+impl InvalidCurveError {
+    pub(crate) fn new(_gamma: f32) -> Self {
+        Self {
+            gamma: _gamma,
+        }
+    }
+}
+
+
+impl std::error::Error for InvalidCurveError {}
+
+
+impl fmt::Display for InvalidCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The exponent {} of the response curve is invalid, it must be finite and greater than 0", self.gamma)
+    }
+}
+
+
+impl fmt::Debug for InvalidCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The exponent {} of the response curve is invalid, it must be finite and greater than 0", self.gamma)
+    }
+}
+
+
+/// `NoRuntimeError` occurs when a background task is started outside a tokio runtime
+pub struct NoRuntimeError {
+    task: &'static str,
+}
+
+
+#[cfg(feature = "tokio")]
+impl NoRuntimeError {
+    pub(crate) fn new(_task: &'static str) -> Self {
+        Self {
+            task: _task,
+        }
+    }
+}
+
+
+impl std::error::Error for NoRuntimeError {}
+
+
+impl fmt::Display for NoRuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> runs on a background task and must be started inside a tokio runtime", self.task)
+    }
+}
+
+
+impl fmt::Debug for NoRuntimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The <{}> runs on a background task and must be started inside a tokio runtime", self.task)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use super::*;
+
+
+    #[test]
+    fn connect_error_source_is_the_inner_error() {
+        let inner = reqwest::Client::new().get("http://").build().unwrap_err();
+        let message = inner.to_string();
+        let err = ConnectError::from(inner);
+
+        let source = err.source().expect("the reqwest error is the source");
+        assert!(source.is::<reqwest::Error>());
+        assert_eq!(source.to_string(), message);
+    }
+
+    #[tokio::test]
+    async fn unreachable_camera_keeps_the_inner_error() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let inner = reqwest::get(format!("http://127.0.0.1:{}", port)).await.unwrap_err();
+        let err = ConnectError::from(inner);
+
+        assert!(matches!(err, ConnectError::Unreachable(_)));
+        assert!(err.source().is_some_and(|source| source.is::<reqwest::Error>()));
+    }
+
+    #[test]
+    fn unauthorized_has_no_source() {
+        assert!(ConnectError::Unauthorized.source().is_none());
+    }
+}
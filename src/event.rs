@@ -0,0 +1,84 @@
+//! Alarm events which the camera pushes over `/ISAPI/Event/notification/alertStream`
+use futures_util::{stream, Stream};
+use crate::{error, xml, Cam, CamResponse};
+
+
+const ALERT_CLOSE: &[u8] = b"</EventNotificationAlert>";
+
+
+/// Alarm event from the camera, e.g. motion detection or line crossing
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlarmEvent {
+    /// Type of the event, e.g. `VMD` for motion detection or `linedetection`
+    pub event_type: String,
+    /// `active` while the event lasts, `inactive` for heartbeats and the end of the event
+    pub event_state: String,
+    pub channel_id: Option<u32>,
+    pub date_time: Option<String>,
+    pub description: Option<String>,
+}
+
+
+impl AlarmEvent {
+    pub(crate) fn parse(body: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self {
+            event_type: xml::tag_value(body, "eventType").ok_or(error::ParseResponseError::new("eventType"))?.to_string(),
+            event_state: xml::tag_value(body, "eventState").ok_or(error::ParseResponseError::new("eventState"))?.to_string(),
+            channel_id: xml::parse_tag(body, "channelID").or_else(|| xml::parse_tag(body, "dynChannelID")),
+            date_time: xml::tag_value(body, "dateTime").map(String::from),
+            description: xml::tag_value(body, "eventDescription").map(String::from),
+        })
+    }
+
+    fn take_from(buffer: &mut Vec<u8>) -> Option<Result<Self, Box<dyn std::error::Error>>> {
+        let end = buffer.windows(ALERT_CLOSE.len()).position(|window| window == ALERT_CLOSE)? + ALERT_CLOSE.len();
+        let part: Vec<u8> = buffer.drain(..end).collect();
+
+        Some(Self::parse(&String::from_utf8_lossy(&part)))
+    }
+}
+
+
+impl Cam {
+    /// Connect to the alert stream of the camera and get the events as they arrive. The parts of the multipart answer
+    /// are split by their `<EventNotificationAlert>` documents. The stream ends when the camera closes the connection,
+    /// to stop listening earlier just drop the stream
+    pub async fn alarm_stream(&self) -> Result<impl Stream<Item = Result<AlarmEvent, Box<dyn std::error::Error>>>, Box<dyn std::error::Error>> {
+        let res = self.send(self.client.get(format!("{}/ISAPI/Event/notification/alertStream", self.host))).await?;
+        let res = Self::check_status(res, "alert stream").await?;
+
+        Ok(stream::unfold(Some((res, Vec::new())), |state| async move {
+            let (mut res, mut buffer) = state?;
+
+            loop {
+                if let Some(event) = AlarmEvent::take_from(&mut buffer) {
+                    return Some((event, Some((res, buffer))));
+                }
+
+                match res.chunk().await {
+                    Ok(Some(chunk)) => buffer.extend_from_slice(&chunk),
+                    Ok(None) => return None,
+                    Err(err) => return Some((Err(Box::new(err) as Box<dyn std::error::Error>), None)),
+                }
+            }
+        }))
+    }
+
+    fn tamper_url(&self, path: &str) -> String {
+        format!("{}/ISAPI/System/Video/inputs/channels/{}/tamperDetection/{}", self.host, self.channel, path)
+    }
+
+    /// Whether the camera currently reports tampering, e.g. a covered or turned away lens
+    pub async fn tamper_status(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.tamper_url("status"), "tamper detection").await?;
+
+        Ok(xml::tag_value(&body, "alarmStatus").ok_or(error::ParseResponseError::new("alarmStatus"))? == "active")
+    }
+
+    /// Acknowledge the tamper alarm so that the camera resets it, the alarm is raised again if tampering goes on
+    pub async fn clear_alarm(&mut self) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let res = self.send(self.client.put(self.tamper_url("alarmReset"))).await?;
+
+        Self::check_supported(res, "tamper detection").await
+    }
+}
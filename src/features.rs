@@ -0,0 +1,59 @@
+//! Summary of the features supported by the camera
+use crate::{xml, Cam};
+
+
+/// What the PTZ of the camera supports, read from the capabilities when connecting
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Features {
+    pub presets: bool,
+    pub patrols: bool,
+    pub absolute: bool,
+    pub continuous: bool,
+    pub momentary: bool,
+    pub focus: bool,
+    pub iris: bool,
+    /// Auxiliary devices like the wiper or the light
+    pub aux: bool,
+    /// Positioning by a rectangle drawn on the picture
+    pub position_3d: bool,
+    /// Number of presets the camera can save, `None` if it is not reported
+    pub max_presets: Option<u16>,
+}
+
+
+fn has_positive(body: &str, tag: &str) -> bool {
+    xml::parse_tag::<u32>(body, tag).is_some_and(|num| num > 0)
+}
+
+
+impl Features {
+    pub(crate) fn parse(body: &str) -> Self {
+        Self {
+            presets: has_positive(body, "maxPresetNum"),
+            patrols: has_positive(body, "maxPatrolNum"),
+            absolute: xml::tag_value(body, "AbsolutePanTiltPositionSpace").is_some(),
+            continuous: xml::tag_value(body, "ContinuousPanTiltSpace").is_some(),
+            momentary: xml::tag_value(body, "MomentaryPanTiltSpace").is_some(),
+            focus: xml::tag_value(body, "ContinuousFocusSpace").is_some() || xml::tag_value(body, "isSupportFocus") == Some("true"),
+            iris: xml::tag_value(body, "ContinuousIrisSpace").is_some() || xml::tag_value(body, "isSupportIris") == Some("true"),
+            aux: has_positive(body, "maxAuxNum") || xml::tag_value(body, "PTZAuxList").is_some(),
+            position_3d: xml::tag_value(body, "isSupportPosition3D") == Some("true"),
+            max_presets: xml::parse_tag(body, "maxPresetNum"),
+        }
+    }
+}
+
+
+impl Cam {
+    /// Features supported by the camera. They are read by `Cam::new` and `CamBuilder::build`, so everything is `false`
+    /// for unchecked constructors or cameras without capabilities
+    pub fn features(&self) -> Features {
+        self.features
+    }
+
+    /// Number of presets the camera can save, the ids of the presets lie in `1..=max_presets`.
+    /// `None` if the camera does not report it
+    pub fn max_presets(&self) -> Option<u16> {
+        self.features.max_presets
+    }
+}
@@ -0,0 +1,42 @@
+//! The same command sent to many cameras at once, e.g. for a video wall
+use futures_util::future;
+use crate::{Cam, CamResponse};
+
+
+/// Command for `broadcast`, each variant calls the method of `Cam` with the same name
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    /// `Cam::rotate_cam`
+    Rotate(i8),
+    /// `Cam::tilt_cam`
+    Tilt(i8),
+    /// `Cam::zoom_cam`
+    Zoom(i8),
+    /// `Cam::move_ptz` with pan, tilt and zoom
+    Move(i8, i8, i8),
+    /// `Cam::start_continuous` with pan, tilt, zoom and the duration in milliseconds
+    Continuous(i8, i8, i8, Option<usize>),
+    /// `Cam::stop_continuous`
+    Stop,
+}
+
+
+impl Cam {
+    async fn run_command(&mut self, cmd: Command) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        match cmd {
+            Command::Rotate(rot) => self.rotate_cam(rot).await,
+            Command::Tilt(tilt) => self.tilt_cam(tilt).await,
+            Command::Zoom(zoom) => self.zoom_cam(zoom).await,
+            Command::Move(pan, tilt, zoom) => self.move_ptz(pan, tilt, zoom).await,
+            Command::Continuous(pan, tilt, zoom, duration_ms) => self.start_continuous(pan, tilt, zoom, duration_ms).await,
+            Command::Stop => self.stop_continuous().await,
+        }
+    }
+}
+
+
+/// Send `cmd` to all `cams` concurrently, the results are in the order of `cams`. Each camera keeps its own throttle,
+/// so a camera that was moved too recently answers `QuickRequsetError` without holding up the others
+pub async fn broadcast(cams: &mut [Cam], cmd: Command) -> Vec<Result<CamResponse, Box<dyn std::error::Error>>> {
+    future::join_all(cams.iter_mut().map(|cam| cam.run_command(cmd))).await
+}
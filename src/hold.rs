@@ -0,0 +1,72 @@
+//! Press and hold emulated with repeated momentary movements
+use std::time::Duration;
+use crate::{capabilities::Range, error, Cam};
+
+
+/// Direction in which the camera moves while it is held
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+    ZoomIn,
+    ZoomOut,
+}
+
+
+/// Handle of the movement started with `Cam::hold`, the movement stops when it is released or dropped
+#[derive(Debug)]
+pub struct HoldHandle {
+    task: tokio::task::JoinHandle<()>,
+}
+
+
+impl HoldHandle {
+    /// Stop sending the movements, the last one finishes within the movement speed
+    pub fn release(self) {}
+}
+
+
+impl Drop for HoldHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+
+impl Cam {
+    /// Keep moving the camera in `dir` with `amount` from 0..=100 until the returned handle is released, like holding
+    /// a button of a control panel. A movement is sent every movement speed on a background task using a copy of the camera,
+    /// outside a tokio runtime `NoRuntimeError` is returned
+    pub fn hold(&mut self, dir: Direction, amount: u8) -> Result<HoldHandle, Box<dyn std::error::Error>> {
+        let range = Range { min: 0, max: 100 };
+        if !range.contains(amount as i32) {
+            return Err(Box::new(error::OutOfRangeValueError::new("amount", amount as i32, range)));
+        }
+
+        let runtime = tokio::runtime::Handle::try_current().map_err(|_| error::NoRuntimeError::new("hold"))?;
+        let mut cam = self.clone();
+        let unit = amount as i8;
+        let period = Duration::from_millis(self.movement_speed as u64);
+
+        let task = runtime.spawn(async move {
+            loop {
+                let _ = match dir {
+                    Direction::Left => cam.rotate_cam(-unit).await,
+                    Direction::Right => cam.rotate_cam(unit).await,
+                    Direction::Up => cam.tilt_cam(unit).await,
+                    Direction::Down => cam.tilt_cam(-unit).await,
+                    Direction::ZoomIn => cam.zoom_cam(unit).await,
+                    Direction::ZoomOut => cam.zoom_cam(-unit).await,
+                };
+
+                tokio::time::sleep(period).await;
+            }
+        });
+
+        Ok(HoldHandle {
+            task,
+        })
+    }
+}
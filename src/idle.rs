@@ -0,0 +1,124 @@
+//! Watchdog which stops the camera if no movement commands arrive for a while
+use std::{sync::{Arc, Mutex}, time::{Duration, Instant}};
+use crate::{error, Cam};
+
+
+const STOP_BODY: &str = "<PTZData>
+                <pan>0</pan>
+                <tilt>0</tilt>
+                <zoom>0</zoom>
+            </PTZData>";
+
+
+#[derive(Debug)]
+struct IdleState {
+    last_command: Instant,
+    moving: bool,
+}
+
+
+#[derive(Debug)]
+pub(crate) struct IdleWatchdog {
+    state: Arc<Mutex<IdleState>>,
+    task: tokio::task::JoinHandle<()>,
+}
+
+
+impl IdleWatchdog {
+    /// `cam` is the copy of the camera which sends the stop, so it goes through the authentication and the limiter
+    fn spawn(runtime: tokio::runtime::Handle, cam: Cam, timeout: Duration) -> Self {
+        let state = Arc::new(Mutex::new(IdleState {
+            last_command: Instant::now(),
+            moving: false,
+        }));
+        let task_state = state.clone();
+
+        let task = runtime.spawn(async move {
+            loop {
+                let sleep_for = {
+                    let state = task_state.lock().unwrap_or_else(|err| err.into_inner());
+
+                    if state.moving {
+                        (state.last_command + timeout).saturating_duration_since(Instant::now())
+                    } else {
+                        timeout
+                    }
+                };
+                tokio::time::sleep(sleep_for).await;
+
+                let need_stop = {
+                    let mut state = task_state.lock().unwrap_or_else(|err| err.into_inner());
+                    let idle = state.moving && state.last_command.elapsed() >= timeout;
+
+                    if idle {
+                        state.moving = false;
+                    }
+
+                    idle
+                };
+
+                if need_stop {
+                    let res = cam.send(cam.client.put(cam.ptz_url("continuous")).body(STOP_BODY)).await;
+                    if matches!(&res, Ok(res) if res.status().is_success()) {
+                        cam.set_continuous_velocity((0, 0, 0), None);
+                    }
+
+                    #[cfg(feature = "tracing")]
+                    match res {
+                        Ok(res) if !res.status().is_success() => tracing::warn!(status = %res.status(), "the camera rejected the stop of the idle watchdog"),
+                        Err(err) => tracing::warn!(error = %err, "the stop of the idle watchdog failed"),
+                        _ => {}
+                    }
+                    #[cfg(not(feature = "tracing"))]
+                    let _ = res;
+                }
+            }
+        });
+
+        Self {
+            state,
+            task,
+        }
+    }
+
+    fn touch(&self, moving: bool) {
+        let mut state = self.state.lock().unwrap_or_else(|err| err.into_inner());
+        state.last_command = Instant::now();
+        state.moving = moving;
+    }
+}
+
+
+impl Drop for IdleWatchdog {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+
+impl Cam {
+    /// Stop the camera if no movement command was sent for `timeout`, so that it is not left moving when the input
+    /// source of a joystick UI is lost. The watchdog runs on a background task which is cancelled when the camera
+    /// object and all its clones are dropped. Outside a tokio runtime `NoRuntimeError` is returned
+    pub fn enable_idle_stop(&mut self, timeout: Duration) -> Result<(), error::NoRuntimeError> {
+        let runtime = tokio::runtime::Handle::try_current().map_err(|_| error::NoRuntimeError::new("idle watchdog"))?;
+        let mut cam = self.clone();
+        cam.idle_watchdog = None;
+        cam.recording = None;
+
+        self.idle_watchdog = Some(Arc::new(IdleWatchdog::spawn(runtime, cam, timeout)));
+
+        Ok(())
+    }
+
+    /// Cancel the watchdog started with `enable_idle_stop`
+    pub fn disable_idle_stop(&mut self) {
+        self.idle_watchdog = None;
+    }
+
+    pub(crate) fn touch_idle(&self, moving: bool) {
+        if let Some(watchdog) = &self.idle_watchdog {
+            watchdog.touch(moving);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Image settings of the camera, useful to adjust the picture for every position of a tour
+use crate::{capabilities::Range, error, xml, Cam, CamResponse};
+
+
+/// Mode of the wide dynamic range
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WdrMode {
+    Open,
+    Close,
+    Auto,
+}
+
+
+impl WdrMode {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Open => "open",
+            Self::Close => "close",
+            Self::Auto => "auto",
+        }
+    }
+
+    fn parse(mode: &str) -> Option<Self> {
+        match mode {
+            "open" => Some(Self::Open),
+            "close" => Some(Self::Close),
+            "auto" => Some(Self::Auto),
+            _ => None,
+        }
+    }
+}
+
+
+const LEVEL_RANGE: Range = Range { min: 0, max: 100 };
+
+
+/// Exposure time of a frame as a fraction of a second, `ShutterSpeed(1000)` is 1/1000s.
+/// A faster shutter gives less motion blur while the camera moves, but a darker picture
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShutterSpeed(pub u32);
+
+
+impl ShutterSpeed {
+    fn as_level(&self) -> String {
+        format!("1/{}", self.0)
+    }
+
+    fn parse(level: &str) -> Option<Self> {
+        level.trim().strip_prefix("1/")?.parse().ok().map(Self)
+    }
+}
+
+
+/// Color settings of the picture, every level is from 0..=100
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColorParams {
+    pub brightness: u8,
+    pub contrast: u8,
+    pub saturation: u8,
+    pub sharpness: u8,
+}
+
+
+impl ColorParams {
+    fn validate(&self) -> Result<(), error::OutOfRangeValueError> {
+        for (field, level) in [
+            ("brightnessLevel", self.brightness),
+            ("contrastLevel", self.contrast),
+            ("saturationLevel", self.saturation),
+            ("SharpnessLevel", self.sharpness),
+        ] {
+            if !LEVEL_RANGE.contains(level as i32) {
+                return Err(error::OutOfRangeValueError::new(field, level as i32, LEVEL_RANGE));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+impl Cam {
+    pub(crate) fn image_url(&self, path: &str) -> String {
+        format!("{}/ISAPI/Image/channels/{}/{}", self.host, self.channel, path)
+    }
+
+    /// Get the WDR mode and its level
+    pub async fn get_wdr(&mut self) -> Result<(WdrMode, u8), Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.image_url("WDR"), "WDR").await?;
+
+        Ok((
+            xml::tag_value(&body, "mode").and_then(WdrMode::parse).ok_or(error::ParseResponseError::new("mode"))?,
+            xml::parse_tag(&body, "WDRLevel").ok_or(error::ParseResponseError::new("WDRLevel"))?,
+        ))
+    }
+
+    /// Set the WDR mode and its level from 0..=100, other WDR settings of the camera are kept as they are
+    pub async fn set_wdr(&mut self, mode: WdrMode, level: u8) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        if !LEVEL_RANGE.contains(level as i32) {
+            return Err(Box::new(error::OutOfRangeValueError::new("WDRLevel", level as i32, LEVEL_RANGE)));
+        }
+
+        let config = self.get_xml(self.image_url("WDR"), "WDR").await?;
+        let config = xml::replace_tag(&config, "mode", mode.as_str()).ok_or(error::ParseResponseError::new("mode"))?;
+        let config = xml::replace_tag(&config, "WDRLevel", &level.to_string()).ok_or(error::ParseResponseError::new("WDRLevel"))?;
+
+        let res = self.send(self.client.put(self.image_url("WDR")).body(config)).await?;
+
+        Self::check_supported(res, "WDR").await
+    }
+
+    /// Get the brightness, contrast, saturation and sharpness of the picture
+    pub async fn get_color(&mut self) -> Result<ColorParams, Box<dyn std::error::Error>> {
+        let color = self.get_xml(self.image_url("color"), "color").await?;
+        let sharpness = self.get_xml(self.image_url("sharpness"), "sharpness").await?;
+
+        Ok(ColorParams {
+            brightness: xml::parse_tag(&color, "brightnessLevel").ok_or(error::ParseResponseError::new("brightnessLevel"))?,
+            contrast: xml::parse_tag(&color, "contrastLevel").ok_or(error::ParseResponseError::new("contrastLevel"))?,
+            saturation: xml::parse_tag(&color, "saturationLevel").ok_or(error::ParseResponseError::new("saturationLevel"))?,
+            sharpness: xml::parse_tag(&sharpness, "SharpnessLevel").ok_or(error::ParseResponseError::new("SharpnessLevel"))?,
+        })
+    }
+
+    /// Change the brightness, contrast, saturation and sharpness of the picture, other image settings are kept as they are.
+    /// The sharpness is a separate setting of the camera, so it is sent after the color
+    pub async fn set_color(&mut self, params: ColorParams) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        params.validate()?;
+
+        let color = self.get_xml(self.image_url("color"), "color").await?;
+        let mut color = xml::replace_tag(&color, "brightnessLevel", &params.brightness.to_string()).ok_or(error::ParseResponseError::new("brightnessLevel"))?;
+        color = xml::replace_tag(&color, "contrastLevel", &params.contrast.to_string()).ok_or(error::ParseResponseError::new("contrastLevel"))?;
+        color = xml::replace_tag(&color, "saturationLevel", &params.saturation.to_string()).ok_or(error::ParseResponseError::new("saturationLevel"))?;
+
+        let res = self.send(self.client.put(self.image_url("color")).body(color)).await?;
+        Self::check_supported(res, "color").await?;
+
+        let sharpness = self.get_xml(self.image_url("sharpness"), "sharpness").await?;
+        let sharpness = xml::replace_tag(&sharpness, "SharpnessLevel", &params.sharpness.to_string()).ok_or(error::ParseResponseError::new("SharpnessLevel"))?;
+
+        let res = self.send(self.client.put(self.image_url("sharpness")).body(sharpness)).await?;
+
+        Self::check_supported(res, "sharpness").await
+    }
+
+    /// Change the shutter speed, it must be one of the speeds the camera offers if it reports them.
+    /// Other exposure settings are kept as they are
+    pub async fn set_shutter(&mut self, value: ShutterSpeed) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let capabilities = self.get_optional_xml(self.image_url("capabilities"), "shutter").await?;
+
+        if let Some(options) = xml::tag_attr(&capabilities, "ShutterLevel", "opt") {
+            let allowed: Vec<i32> = options.split(',').filter_map(ShutterSpeed::parse).map(|speed| speed.0 as i32).collect();
+
+            if !allowed.contains(&(value.0 as i32)) {
+                return Err(Box::new(error::NotAllowedValueError::new("ShutterLevel", value.0 as i32, allowed)));
+            }
+        }
+
+        let config = self.get_xml(self.image_url("shutter"), "shutter").await?;
+        let config = xml::replace_tag(&config, "ShutterLevel", &value.as_level()).ok_or(error::ParseResponseError::new("ShutterLevel"))?;
+
+        let res = self.send(self.client.put(self.image_url("shutter")).body(config)).await?;
+
+        Self::check_supported(res, "shutter").await
+    }
+}
@@ -0,0 +1,27 @@
+//! Alarm outputs of the device, e.g. a siren or a light connected to the camera
+use crate::{error, xml, Cam, CamResponse};
+
+
+impl Cam {
+    /// Get the ids of the alarm outputs of the device
+    pub async fn list_outputs(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let body = self.get_xml(format!("{}/ISAPI/System/IO/outputs", self.host), "alarm outputs").await?;
+
+        xml::tag_values(&body, "IOOutputPort").into_iter()
+            .map(|port| xml::parse_tag(port, "id").ok_or_else(|| error::ParseResponseError::new("id").into()))
+            .collect()
+    }
+
+    /// Switch the alarm output `id` on or off, `OutputNotFoundError` is returned if the device does not have it
+    pub async fn trigger_output(&mut self, id: u8, active: bool) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        if !self.list_outputs().await?.contains(&id) {
+            return Err(Box::new(error::OutputNotFoundError::new(id)));
+        }
+
+        let res = self.send(self.client.put(format!("{}/ISAPI/System/IO/outputs/{}/trigger", self.host, id)).body(format!("<IOPortData>
+                <outputState>{}</outputState>
+            </IOPortData>", if active { "high" } else { "low" }))).await?;
+
+        Self::check_supported(res, "alarm outputs").await
+    }
+}
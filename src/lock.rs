@@ -0,0 +1,68 @@
+//! Exclusive control of the PTZ through the lock of the camera
+use crate::{Cam, CamResponse};
+
+
+/// Session of the locked PTZ returned by `Cam::lock`, other clients cannot move the camera while it lives.
+/// Release it with `release` to know whether unlocking succeeded, if it is dropped instead the camera is unlocked
+/// on a background task and a failure is only logged with the `tracing` feature
+#[derive(Debug)]
+pub struct PtzSession {
+    cam: Option<Cam>,
+}
+
+
+impl PtzSession {
+    /// Unlock the PTZ of the camera
+    pub async fn release(mut self) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        match self.cam.take() {
+            Some(mut cam) => cam.set_ptz_lock(false).await,
+            None => unreachable!("the camera is taken only by `release` and `drop`"),
+        }
+    }
+}
+
+
+impl Drop for PtzSession {
+    fn drop(&mut self) {
+        let Some(mut cam) = self.cam.take() else {
+            return;
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!("the PTZ session was dropped without `release`, unlocking on a background task");
+
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("the PTZ session was dropped outside a tokio runtime, the camera stays locked");
+            return;
+        };
+
+        runtime.spawn(async move {
+            if let Err(_err) = cam.set_ptz_lock(false).await {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %_err, "unlocking the PTZ of the dropped session failed, the camera stays locked");
+            }
+        });
+    }
+}
+
+
+impl Cam {
+    async fn set_ptz_lock(&mut self, enabled: bool) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let res = self.send(self.client.put(self.ptz_url("lockPTZ")).body(format!("<PTZLockCfg>
+                <enabled>{}</enabled>
+            </PTZLockCfg>", enabled))).await?;
+
+        Self::check_supported(res, "PTZ lock").await
+    }
+
+    /// Lock the PTZ so that only this client can move the camera until the returned session is released.
+    /// The session uses a copy of the camera, so the camera can still be moved through `self` meanwhile
+    pub async fn lock(&mut self) -> Result<PtzSession, Box<dyn std::error::Error>> {
+        self.set_ptz_lock(true).await?;
+
+        Ok(PtzSession {
+            cam: Some(self.clone()),
+        })
+    }
+}
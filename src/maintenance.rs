@@ -0,0 +1,73 @@
+//! Maintenance of the PTZ mechanics
+use std::time::{Duration, Instant};
+use crate::{xml, Cam};
+
+
+/// How often the progress of the self-check is read
+const SELF_CHECK_POLL: Duration = Duration::from_secs(1);
+/// How long the self-check may take before `calibrate_ptz` gives up waiting
+const SELF_CHECK_TIMEOUT: Duration = Duration::from_secs(180);
+/// How long a camera which does not report the progress must have been running the self-check before standing still
+/// counts as finished, if it was never seen moving
+const SELF_CHECK_MIN_DURATION: Duration = Duration::from_secs(15);
+
+
+/// Result of `calibrate_ptz`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PtzCalibration {
+    /// Whether the camera finished the self-check before the timeout
+    pub completed: bool,
+    /// Last progress in percent, `None` if the firmware does not report it
+    pub progress: Option<u8>,
+}
+
+
+impl Cam {
+    /// Start the self-check of the PTZ, during which the camera finds its mechanical reference again, and wait until it
+    /// finishes, at most 3 minutes. The end is taken from the progress reported by the firmware, or from the camera
+    /// standing still after it moved or after 15 seconds if the progress is not reported, failed reads are polled again.
+    /// Use it when presets and absolute positions start to drift
+    pub async fn calibrate_ptz(&mut self) -> Result<PtzCalibration, Box<dyn std::error::Error>> {
+        let res = self.send(self.client.put(self.ptz_url("ptzSelfCheck"))).await?;
+        Self::check_supported(res, "PTZ self-check").await?;
+
+        let start = Instant::now();
+        let mut progress = None;
+        let mut moved = false;
+
+        while start.elapsed() < SELF_CHECK_TIMEOUT {
+            tokio::time::sleep(SELF_CHECK_POLL).await;
+
+            let reported = match self.get_xml(self.ptz_url("ptzSelfCheck"), "PTZ self-check").await {
+                Ok(body) => xml::parse_tag::<u8>(&body, "progress"),
+                Err(_) => None,
+            };
+
+            match reported {
+                Some(percent) if percent >= 100 => return Ok(PtzCalibration {
+                    completed: true,
+                    progress: Some(100),
+                }),
+                Some(percent) => progress = Some(percent),
+                None if progress.is_none() => match self.is_moving().await {
+                    Ok(true) => moved = true,
+                    Ok(false) if moved || start.elapsed() >= SELF_CHECK_MIN_DURATION => return Ok(PtzCalibration {
+                        completed: true,
+                        progress: None,
+                    }),
+                    Ok(false) => {}
+                    Err(_err) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %_err, "reading the movement during the PTZ self-check failed, polling again");
+                    }
+                },
+                None => {}
+            }
+        }
+
+        Ok(PtzCalibration {
+            completed: false,
+            progress,
+        })
+    }
+}
@@ -0,0 +1,100 @@
+//! Grid of the motion detection, the cells of the picture where motion triggers an event
+use crate::{error, xml, Cam, CamResponse};
+
+
+/// Cells of the motion detection grid, `cells[row][column]` is `true` where motion is detected
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MotionGrid {
+    pub cells: Vec<Vec<bool>>,
+}
+
+
+impl MotionGrid {
+    /// Grid of `rows` x `columns` cells with nothing enabled
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            cells: vec![vec![false; columns]; rows],
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.cells.first().map_or(0, Vec::len)
+    }
+
+    fn size(body: &str) -> Result<(usize, usize), Box<dyn std::error::Error>> {
+        Ok((
+            xml::parse_tag(body, "rowGranularity").ok_or(error::ParseResponseError::new("rowGranularity"))?,
+            xml::parse_tag(body, "columnGranularity").ok_or(error::ParseResponseError::new("columnGranularity"))?,
+        ))
+    }
+
+    /// Every row takes whole bytes of the hex `gridMap`, the first column is the highest bit
+    fn row_bytes(columns: usize) -> usize {
+        columns.div_ceil(8)
+    }
+
+    pub(crate) fn parse(body: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let (rows, columns) = Self::size(body)?;
+        let map = xml::tag_value(body, "gridMap").filter(|map| map.is_ascii()).ok_or(error::ParseResponseError::new("gridMap"))?;
+        let bytes: Vec<u8> = (0..map.len() / 2)
+            .map(|i| u8::from_str_radix(&map[i * 2..i * 2 + 2], 16))
+            .collect::<Result<_, _>>()
+            .map_err(|_| error::ParseResponseError::new("gridMap"))?;
+
+        let row_bytes = Self::row_bytes(columns);
+        if bytes.len() < rows * row_bytes {
+            return Err(Box::new(error::ParseResponseError::new("gridMap")));
+        }
+
+        Ok(Self {
+            cells: (0..rows).map(|row| {
+                (0..columns).map(|column| bytes[row * row_bytes + column / 8] & (0x80 >> (column % 8)) != 0).collect()
+            }).collect(),
+        })
+    }
+
+    fn grid_map(&self) -> String {
+        let row_bytes = Self::row_bytes(self.columns());
+
+        self.cells.iter().flat_map(|row| {
+            (0..row_bytes).map(move |byte| {
+                row.iter().skip(byte * 8).take(8).enumerate()
+                    .fold(0u8, |acc, (bit, cell)| if *cell { acc | (0x80 >> bit) } else { acc })
+            })
+        }).map(|byte| format!("{:02x}", byte)).collect()
+    }
+}
+
+
+impl Cam {
+    fn motion_url(&self) -> String {
+        format!("{}/ISAPI/System/Video/inputs/channels/{}/motionDetection", self.host, self.channel)
+    }
+
+    /// Get the cells of the motion detection grid
+    pub async fn get_motion_grid(&mut self) -> Result<MotionGrid, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.motion_url(), "motion detection").await?;
+
+        MotionGrid::parse(&body)
+    }
+
+    /// Replace the cells of the motion detection grid, `grid` must have as many rows and columns as the camera reports,
+    /// see `get_motion_grid`. Other settings of the motion detection are kept as they are
+    pub async fn set_motion_grid(&mut self, grid: &MotionGrid) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let config = self.get_xml(self.motion_url(), "motion detection").await?;
+
+        let expected = MotionGrid::size(&config)?;
+        if grid.cells.iter().any(|row| row.len() != expected.1) || grid.rows() != expected.0 {
+            return Err(Box::new(error::GridSizeError::new(expected, (grid.rows(), grid.columns()))));
+        }
+
+        let config = xml::replace_tag(&config, "gridMap", &grid.grid_map()).ok_or(error::ParseResponseError::new("gridMap"))?;
+        let res = self.send(self.client.put(self.motion_url()).body(config)).await?;
+
+        Self::check_supported(res, "motion detection").await
+    }
+}
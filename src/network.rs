@@ -0,0 +1,64 @@
+//! Network settings of the camera
+use std::net::Ipv4Addr;
+use crate::{error, xml, Cam};
+
+
+/// IPv4 settings of the network interface of the camera
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NetworkConfig {
+    /// Whether the address is obtained by DHCP, the other fields are then the ones given by the DHCP server
+    pub dhcp: bool,
+    pub address: Ipv4Addr,
+    pub mask: Ipv4Addr,
+    pub gateway: Option<Ipv4Addr>,
+}
+
+
+impl NetworkConfig {
+    pub(crate) fn parse(body: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self {
+            dhcp: xml::tag_value(body, "addressingType") == Some("dynamic"),
+            address: xml::parse_tag(body, "ipAddress").ok_or(error::ParseResponseError::new("ipAddress"))?,
+            mask: xml::parse_tag(body, "subnetMask").ok_or(error::ParseResponseError::new("subnetMask"))?,
+            gateway: xml::tag_value(body, "DefaultGateway").and_then(|gateway| xml::parse_tag(gateway, "ipAddress")),
+        })
+    }
+}
+
+
+impl Cam {
+    fn network_url(&self) -> String {
+        format!("{}/ISAPI/System/Network/interfaces/1/ipAddress", self.host)
+    }
+
+    /// Get the IPv4 settings of the first network interface
+    pub async fn network_config(&mut self) -> Result<NetworkConfig, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.network_url(), "network").await?;
+
+        NetworkConfig::parse(&body)
+    }
+
+    /// Change the IPv4 settings of the first network interface, other settings of the interface are kept as they are.
+    /// When the address changes, the camera may drop the connection before answering, so a connection lost after the
+    /// request was sent counts as success. This `Cam` keeps using the old address, connect again to the new one
+    pub async fn set_network_config(&mut self, config: NetworkConfig) -> Result<(), Box<dyn std::error::Error>> {
+        let current = self.get_xml(self.network_url(), "network").await?;
+
+        let mut body = xml::replace_tag(&current, "addressingType", if config.dhcp { "dynamic" } else { "static" })
+            .ok_or(error::ParseResponseError::new("addressingType"))?;
+        body = xml::replace_tag(&body, "ipAddress", &config.address.to_string()).ok_or(error::ParseResponseError::new("ipAddress"))?;
+        body = xml::replace_tag(&body, "subnetMask", &config.mask.to_string()).ok_or(error::ParseResponseError::new("subnetMask"))?;
+        if let Some(gateway) = config.gateway {
+            body = xml::replace_tag(&body, "DefaultGateway", &format!("<ipAddress>{}</ipAddress>", gateway))
+                .ok_or(error::ParseResponseError::new("DefaultGateway"))?;
+        }
+
+        match self.send(self.client.put(self.network_url()).body(body)).await {
+            Ok(res) => Self::check_supported(res, "network").await.map(|_| ()),
+            Err(err) => match err.downcast_ref::<reqwest::Error>() {
+                Some(err) if !err.is_connect() && (err.is_request() || err.is_body() || err.is_timeout()) => Ok(()),
+                _ => Err(err),
+            },
+        }
+    }
+}
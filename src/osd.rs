@@ -0,0 +1,38 @@
+//! On-screen display drawn over the video
+use crate::{error, xml, Cam, CamResponse};
+
+
+/// Date formats of the overlay accepted by the camera
+pub const DATE_FORMATS: [&str; 6] = [
+    "YYYY-MM-DD",
+    "MM-DD-YYYY",
+    "DD-MM-YYYY",
+    "CHR-YYYY-MM-DD",
+    "CHR-MM-DD-YYYY",
+    "CHR-DD-MM-YYYY",
+];
+
+
+impl Cam {
+    fn datetime_overlay_url(&self) -> String {
+        format!("{}/ISAPI/System/Video/inputs/channels/{}/overlays/dateTimeOverlay", self.host, self.channel)
+    }
+
+    /// Show or hide the date and time stamp. `format` is one of [`DATE_FORMATS`] and `position` is the top left corner of the
+    /// stamp in the coordinates of the overlay. Other settings of the stamp, e.g. the time style, are kept as they are
+    pub async fn set_datetime_overlay(&mut self, enabled: bool, format: &str, position: (u16, u16)) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        if !DATE_FORMATS.contains(&format) {
+            return Err(Box::new(error::InvalidDateFormatError::new(format.to_string())));
+        }
+
+        let config = self.get_xml(self.datetime_overlay_url(), "date and time overlay").await?;
+        let mut config = xml::replace_tag(&config, "enabled", &enabled.to_string()).ok_or(error::ParseResponseError::new("enabled"))?;
+        config = xml::replace_tag(&config, "positionX", &position.0.to_string()).ok_or(error::ParseResponseError::new("positionX"))?;
+        config = xml::replace_tag(&config, "positionY", &position.1.to_string()).ok_or(error::ParseResponseError::new("positionY"))?;
+        config = xml::replace_tag(&config, "dateStyle", &xml::escape(format)).ok_or(error::ParseResponseError::new("dateStyle"))?;
+
+        let res = self.send(self.client.put(self.datetime_overlay_url()).body(config)).await?;
+
+        Self::check_supported(res, "date and time overlay").await
+    }
+}
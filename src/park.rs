@@ -0,0 +1,49 @@
+//! Automatic return of the camera to the default view after inactivity
+use crate::{error, Cam, CamResponse};
+
+
+/// What the camera does after it has been left alone for the park delay
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParkAction {
+    /// Do nothing, parking is disabled
+    None,
+    /// Go to the preset with this id
+    Preset(u16),
+    /// Run the patrol with this id
+    Patrol(u8),
+}
+
+
+impl Cam {
+    /// Set what the camera does after `delay_secs` seconds without commands. The referenced preset or patrol must exist
+    pub async fn set_park_action(&mut self, action: ParkAction, delay_secs: u32) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let (enabled, action_type, action_num) = match action {
+            ParkAction::None => (false, "preset", 1),
+            ParkAction::Preset(id) => {
+                if !self.get_presets().await?.iter().any(|preset| preset.id == id) {
+                    return Err(Box::new(error::PresetNotFoundError::new(id)));
+                }
+
+                (true, "preset", id)
+            },
+            ParkAction::Patrol(id) => {
+                if !self.get_patrol_ids().await?.contains(&id) {
+                    return Err(Box::new(error::PatrolNotFoundError::new(id)));
+                }
+
+                (true, "patrol", id as u16)
+            },
+        };
+
+        let res = self.send(self.client.put(self.ptz_url("ParkAction")).body(format!("<ParkAction>
+                <enabled>{}</enabled>
+                <Parktime>{}</Parktime>
+                <Action>
+                    <ActionType>{}</ActionType>
+                    <ActionNum>{}</ActionNum>
+                </Action>
+            </ParkAction>", enabled, delay_secs, action_type, action_num))).await?;
+
+        Self::check_supported(res, "park action").await
+    }
+}
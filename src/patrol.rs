@@ -0,0 +1,78 @@
+//! Patrols, the camera goes through the sequence of presets by itself
+use crate::{error, xml, Cam, CamResponse};
+
+
+/// How many points a patrol can have if the camera does not report it in its capabilities
+const DEFAULT_MAX_PATROL_POINTS: usize = 32;
+
+
+/// One stop of the patrol
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PatrolPoint {
+    /// Preset to which the camera goes
+    pub preset_id: u16,
+    /// How long the camera stays at the preset in seconds
+    pub dwell_secs: u32,
+    /// Speed with which the camera moves to the preset
+    pub speed: u8,
+}
+
+
+impl Cam {
+    /// Create or replace the patrol `id` with the given points, then it can be started with `start_patrol`.
+    /// Every preset must exist on the camera and the number of points must not exceed the camera's limit
+    pub async fn set_patrol(&mut self, id: u8, points: Vec<PatrolPoint>) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let capabilities = if self.capabilities_available {
+            self.capabilities().await?
+        } else {
+            String::new()
+        };
+        let max_points = xml::parse_tag(&capabilities, "maxPatrolSequenceNum").unwrap_or(DEFAULT_MAX_PATROL_POINTS);
+        if points.len() > max_points {
+            return Err(Box::new(error::PatrolTooLongError::new(points.len(), max_points)));
+        }
+
+        let presets = self.get_presets().await?;
+        for point in points.iter() {
+            if !presets.iter().any(|preset| preset.id == point.preset_id) {
+                return Err(Box::new(error::PresetNotFoundError::new(point.preset_id)));
+            }
+        }
+
+        let sequence: String = points.iter().map(|point| format!("<PatrolSequence>
+                    <presetID>{}</presetID>
+                    <delay>{}</delay>
+                    <speed>{}</speed>
+                </PatrolSequence>", point.preset_id, point.dwell_secs, point.speed)).collect();
+
+        let res = self.send(self.client.put(self.ptz_url(&format!("patrols/{}", id))).body(format!("<PTZPatrol>
+                <id>{}</id>
+                <PatrolSequenceList>{}</PatrolSequenceList>
+            </PTZPatrol>", id, sequence))).await?;
+
+        Self::check_supported(res, "patrols").await
+    }
+
+    /// Get the ids of all patrols defined on the camera
+    pub async fn get_patrol_ids(&mut self) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.ptz_url("patrols"), "patrols").await?;
+
+        xml::tag_values(&body, "PTZPatrol").into_iter()
+            .map(|patrol| xml::parse_tag(patrol, "id").ok_or_else(|| Box::new(error::ParseResponseError::new("id")).into()))
+            .collect()
+    }
+
+    /// Start the patrol `id`
+    pub async fn start_patrol(&mut self, id: u8) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let res = self.send(self.client.put(self.ptz_url(&format!("patrols/{}/start", id)))).await?;
+
+        Self::check_supported(res, "patrols").await
+    }
+
+    /// Stop the patrol `id`
+    pub async fn stop_patrol(&mut self, id: u8) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let res = self.send(self.client.put(self.ptz_url(&format!("patrols/{}/stop", id)))).await?;
+
+        Self::check_supported(res, "patrols").await
+    }
+}
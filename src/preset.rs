@@ -0,0 +1,197 @@
+//! Presets saved on the camera
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use crate::{error, record, status::PtzStatus, xml, Cam, CamResponse};
+
+
+/// Preset saved on the camera
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Preset {
+    pub id: u16,
+    pub name: String,
+    /// Position of the preset, `None` if the firmware does not report it in the list of presets
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub position: Option<PtzStatus>,
+}
+
+
+impl Preset {
+    pub(crate) fn parse_list(body: &str) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut presets = Vec::new();
+
+        for preset in xml::tag_values(body, "PTZPreset") {
+            if xml::tag_value(preset, "enabled") == Some("false") {
+                continue;
+            }
+
+            presets.push(Self {
+                id: xml::parse_tag(preset, "id").ok_or(error::ParseResponseError::new("id"))?,
+                name: xml::unescape(xml::tag_value(preset, "presetName").unwrap_or_default()),
+                position: xml::tag_value(preset, "AbsoluteHigh").and_then(|position| PtzStatus::parse(position).ok()),
+            });
+        }
+
+        Ok(presets)
+    }
+}
+
+
+/// Result of `clear_all_presets`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClearedPresets {
+    /// Number of deleted presets
+    pub removed: usize,
+    /// Presets which the camera failed to delete
+    pub failed: Vec<u16>,
+}
+
+
+impl Cam {
+    /// Get all presets saved on the camera
+    pub async fn get_presets(&mut self) -> Result<Vec<Preset>, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.ptz_url("presets"), "presets").await?;
+
+        Preset::parse_list(&body)
+    }
+
+    /// Save the current position of the camera as the preset `id` named `name`, an existing preset is overwritten.
+    /// `PresetLimitExceededError` is returned if `id` is beyond `max_presets`
+    pub async fn set_preset(&mut self, id: u16, name: &str) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        self.check_preset_id(id)?;
+
+        let res = self.send(self.client.put(self.ptz_url(&format!("presets/{}", id))).body(format!("<PTZPreset>
+                <id>{}</id>
+                <presetName>{}</presetName>
+            </PTZPreset>", id, xml::escape(name)))).await?;
+
+        Self::check_supported(res, "presets").await
+    }
+
+    fn check_preset_id(&self, id: u16) -> Result<(), error::PresetLimitExceededError> {
+        match self.max_presets() {
+            Some(max) if id == 0 || id > max => Err(error::PresetLimitExceededError::new(id, max)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the JPEG thumbnail which the camera stored for the preset `id`, to show a preview of the position.
+    /// `UnsupportedFeatureError` is returned by cameras which do not store thumbnails, and `PresetLimitExceededError`
+    /// if `id` is beyond `max_presets`
+    pub async fn preset_thumbnail(&mut self, id: u16) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        self.check_preset_id(id)?;
+
+        let res = self.send(self.client.get(self.ptz_url(&format!("presets/{}/picture", id)))).await?;
+        let picture = Self::check_status(res, "preset thumbnails").await?.bytes().await?;
+
+        Ok(picture.to_vec())
+    }
+
+    /// Move the camera to the preset `id`
+    pub async fn goto_preset(&mut self, id: u16) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        self.record(record::RecordedAction::Preset { id });
+        let res = self.send(self.client.put(self.ptz_url(&format!("presets/{}/goto", id)))).await?;
+
+        Self::check_supported(res, "presets").await
+    }
+
+    /// Delete the preset `id` from the camera
+    pub async fn delete_preset(&mut self, id: u16) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let res = self.send(self.client.delete(self.ptz_url(&format!("presets/{}", id)))).await?;
+
+        Self::check_supported(res, "presets").await
+    }
+
+    /// Delete all presets saved on the camera. Deleting continues if some preset fails, such presets are listed in the result
+    pub async fn clear_all_presets(&mut self) -> Result<ClearedPresets, Box<dyn std::error::Error>> {
+        let mut cleared = ClearedPresets::default();
+
+        for preset in self.get_presets().await? {
+            match self.delete_preset(preset.id).await {
+                Ok(_) => cleared.removed += 1,
+                Err(_) => cleared.failed.push(preset.id),
+            }
+        }
+
+        Ok(cleared)
+    }
+
+    /// How many times `is_moving` is asked before the camera is taken as stopped anyway
+    #[cfg(all(feature = "serde", feature = "tokio"))]
+    const MAX_SETTLE_CHECKS: usize = 50;
+
+    /// Wait until the movement which was just started is over, at least one sample interval of `is_moving` passes
+    /// before every check so that a movement which has not begun yet is not taken for a stop
+    #[cfg(all(feature = "serde", feature = "tokio"))]
+    async fn wait_until_stopped(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let interval = std::time::Duration::from_millis(self.moving_sample_interval as u64);
+
+        for _ in 0..Self::MAX_SETTLE_CHECKS {
+            tokio::time::sleep(interval).await;
+
+            if !self.is_moving().await? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Export all presets as a JSON array of `{id, name, position}` to copy them to another camera with `import_presets`.
+    /// If the firmware does not report the position of a preset, the camera is moved to it to read the position and
+    /// returned to where it stood afterwards. Without the `tokio` feature the position of such presets is `null`
+    #[cfg(feature = "serde")]
+    pub async fn export_presets(&mut self) -> Result<String, Box<dyn std::error::Error>> {
+        #[allow(unused_mut)]
+        let mut presets = self.get_presets().await?;
+
+        #[cfg(feature = "tokio")]
+        if presets.iter().any(|preset| preset.position.is_none()) {
+            let origin = self.get_status().await?;
+
+            for preset in presets.iter_mut().filter(|preset| preset.position.is_none()) {
+                self.goto_preset(preset.id).await?;
+                self.wait_until_stopped().await?;
+                preset.position = Some(self.get_status().await?);
+            }
+
+            self.move_absolute(origin.azimuth, origin.elevation, origin.absolute_zoom).await?;
+        }
+
+        Ok(serde_json::to_string(&presets)?)
+    }
+
+    /// Recreate the presets exported by `export_presets`, returns the number of created presets. The camera is moved to
+    /// every position and the preset is saved once it stops, positions outside the mechanical range of this camera are
+    /// clamped to it. Presets without a position are saved at wherever the camera stands
+    #[cfg(all(feature = "serde", feature = "tokio"))]
+    pub async fn import_presets(&mut self, json: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        let presets: Vec<Preset> = serde_json::from_str(json)?;
+        let capabilities = if self.capabilities_available {
+            self.get_capabilities().await?
+        } else {
+            Default::default()
+        };
+
+        for preset in &presets {
+            if let Some(position) = preset.position {
+                let clamp = |range: Option<crate::capabilities::Range>, value: i32| match range {
+                    Some(range) => value.clamp(range.min, range.max),
+                    None => value,
+                };
+
+                self.move_absolute(
+                    clamp(capabilities.absolute_pan, position.azimuth),
+                    clamp(capabilities.absolute_tilt, position.elevation),
+                    clamp(capabilities.absolute_zoom, position.absolute_zoom),
+                ).await?;
+
+                self.wait_until_stopped().await?;
+            }
+
+            self.set_preset(preset.id, &preset.name).await?;
+        }
+
+        Ok(presets.len())
+    }
+}
@@ -0,0 +1,55 @@
+//! Privacy masks, areas of the picture which the camera hides. PTZ cameras move the masks together with the view
+use crate::{error, region::Region, xml, Cam, CamResponse};
+
+
+/// Privacy mask configured on the camera
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrivacyMask {
+    pub id: u8,
+    pub enabled: bool,
+    pub region: Region,
+}
+
+
+impl PrivacyMask {
+    pub(crate) fn parse_list(body: &str) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut masks = Vec::new();
+
+        for mask in xml::tag_values(body, "PrivacyMaskRegion") {
+            masks.push(Self {
+                id: xml::parse_tag(mask, "id").ok_or(error::ParseResponseError::new("id"))?,
+                enabled: xml::tag_value(mask, "enabled") == Some("true"),
+                region: Region::parse(mask).ok_or(error::ParseResponseError::new("RegionCoordinatesList"))?,
+            });
+        }
+
+        Ok(masks)
+    }
+}
+
+
+impl Cam {
+    fn privacy_mask_url(&self) -> String {
+        format!("{}/ISAPI/System/Video/inputs/channels/{}/privacyMask", self.host, self.channel)
+    }
+
+    /// Get all privacy masks of the channel
+    pub async fn get_privacy_masks(&mut self) -> Result<Vec<PrivacyMask>, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.privacy_mask_url(), "privacy mask").await?;
+
+        PrivacyMask::parse_list(&body)
+    }
+
+    /// Create or replace the privacy mask `id`, the region must lie inside the picture
+    pub async fn set_privacy_mask(&mut self, id: u8, region: Region, enabled: bool) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        region.validate()?;
+
+        let res = self.send(self.client.put(format!("{}/regions/{}", self.privacy_mask_url(), id)).body(format!("<PrivacyMaskRegion>
+                <id>{}</id>
+                <enabled>{}</enabled>
+                {}
+            </PrivacyMaskRegion>", id, enabled, region.coordinates_xml()))).await?;
+
+        Self::check_supported(res, "privacy mask").await
+    }
+}
@@ -0,0 +1,126 @@
+//! Recording of the commands sent to the camera and replaying them later with the same timing
+use std::time;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use crate::Cam;
+
+
+/// Movement that was sent to the camera, the values are exactly the ones that were sent
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RecordedAction {
+    /// Momentary movement, e.g. from `rotate_cam` or `move_ptz`
+    Momentary {
+        pan: i8,
+        tilt: i8,
+        zoom: i8,
+        duration_ms: usize,
+    },
+    /// Continuous movement from `start_continuous` or `stop_continuous`
+    Continuous {
+        pan: i8,
+        tilt: i8,
+        zoom: i8,
+        duration_ms: Option<usize>,
+    },
+    /// Continuous movement with the lens from `continuous_full`
+    ContinuousFull {
+        pan: i8,
+        tilt: i8,
+        zoom: i8,
+        focus: i8,
+        iris: i8,
+    },
+    /// Absolute movement from `move_absolute` or `point_at`, in the units of `move_absolute`
+    Absolute {
+        azimuth: i32,
+        elevation: i32,
+        zoom: i32,
+    },
+    /// Movement to a preset from `goto_preset`
+    Preset {
+        id: u16,
+    },
+}
+
+
+/// Command with the time at which it was sent, counted from the start of the recording
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RecordedCommand {
+    pub action: RecordedAction,
+    pub at: time::Duration,
+}
+
+
+#[derive(Clone, Debug)]
+pub(crate) struct Recording {
+    started: time::Instant,
+    commands: Vec<RecordedCommand>,
+}
+
+
+impl Cam {
+    /// Start recording every movement sent to the camera, a recording in progress is discarded
+    pub fn start_recording(&mut self) {
+        self.recording = Some(Recording {
+            started: time::Instant::now(),
+            commands: Vec::new(),
+        });
+    }
+
+    /// Stop recording and get the recorded commands, an empty list is returned if the recording was not started
+    pub fn stop_recording(&mut self) -> Vec<RecordedCommand> {
+        self.recording.take().map(|recording| recording.commands).unwrap_or_default()
+    }
+
+    pub(crate) fn record(&mut self, action: RecordedAction) {
+        if let Some(recording) = self.recording.as_mut() {
+            recording.commands.push(RecordedCommand {
+                action,
+                at: recording.started.elapsed(),
+            });
+        }
+    }
+}
+
+
+/// Send the recorded commands to `cam` keeping the time between them. Throttling is not applied
+/// because the timing is taken from the recording
+#[cfg(feature = "tokio")]
+pub async fn replay(cam: &mut Cam, commands: &[RecordedCommand]) -> Result<(), Box<dyn std::error::Error>> {
+    let started = time::Instant::now();
+
+    for command in commands {
+        tokio::time::sleep((started + command.at).saturating_duration_since(time::Instant::now())).await;
+
+        match command.action {
+            RecordedAction::Momentary { pan, tilt, zoom, duration_ms } => {
+                cam.pan = pan;
+                cam.tilt = tilt;
+                cam.zoom = zoom;
+
+                let answer = cam.send_data_for(duration_ms).await;
+                cam.pan = 0;
+                cam.tilt = 0;
+                cam.zoom = 0;
+
+                answer?;
+            },
+            RecordedAction::Continuous { pan, tilt, zoom, duration_ms } => {
+                cam.send_continuous(pan, tilt, zoom, duration_ms).await?;
+            },
+            RecordedAction::ContinuousFull { pan, tilt, zoom, focus, iris } => {
+                cam.send_continuous_full(pan, tilt, zoom, focus, iris).await?;
+            },
+            RecordedAction::Absolute { azimuth, elevation, zoom } => {
+                cam.move_absolute(azimuth, elevation, zoom).await?;
+            },
+            RecordedAction::Preset { id } => {
+                cam.goto_preset(id).await?;
+            },
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! Rectangular regions of the picture, used by privacy masks and detection areas
+use crate::{capabilities::Range, error, xml};
+
+
+/// Side of the picture in the normalized coordinates of the camera
+pub const REGION_SCALE: u16 = 1000;
+
+
+/// Rectangle in the normalized coordinates of the camera from 0..=1000, `(x, y)` is the lower left corner
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+
+impl Region {
+    /// Check that the region is not empty and lies inside the picture
+    pub fn validate(&self) -> Result<(), error::OutOfRangeValueError> {
+        let range = Range { min: 1, max: REGION_SCALE as i32 };
+
+        let (x, y, width, height) = (self.x as i32, self.y as i32, self.width as i32, self.height as i32);
+
+        for (field, value) in [("width", width), ("height", height), ("x + width", x + width), ("y + height", y + height)] {
+            if !range.contains(value) {
+                return Err(error::OutOfRangeValueError::new(field, value, range));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn coordinates_xml(&self) -> String {
+        let corners = [(self.x, self.y), (self.x, self.y + self.height), (self.x + self.width, self.y + self.height), (self.x + self.width, self.y)];
+
+        let coordinates: String = corners.iter().map(|(x, y)| format!("<RegionCoordinates>
+                    <positionX>{}</positionX>
+                    <positionY>{}</positionY>
+                </RegionCoordinates>", x, y)).collect();
+
+        format!("<RegionCoordinatesList>{}</RegionCoordinatesList>", coordinates)
+    }
+
+    /// Bounding box of the `<RegionCoordinates>` points
+    pub(crate) fn parse(body: &str) -> Option<Self> {
+        let points: Vec<(u16, u16)> = xml::tag_values(body, "RegionCoordinates").into_iter()
+            .filter_map(|point| Some((xml::parse_tag(point, "positionX")?, xml::parse_tag(point, "positionY")?)))
+            .collect();
+
+        let min_x = points.iter().map(|point| point.0).min()?;
+        let min_y = points.iter().map(|point| point.1).min()?;
+        let max_x = points.iter().map(|point| point.0).max()?;
+        let max_y = points.iter().map(|point| point.1).max()?;
+
+        Some(Self {
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x,
+            height: max_y - min_y,
+        })
+    }
+}
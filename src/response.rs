@@ -0,0 +1,60 @@
+//! Answer of the camera to a command
+use std::time::Duration;
+use reqwest::{Response, StatusCode};
+use crate::{xml, RoundTrip};
+
+
+/// Answer of the camera to a command. The body is read once when the answer arrives, the fields of its
+/// `<ResponseStatus>` are parsed only when asked for
+#[derive(Clone, Debug)]
+pub struct CamResponse {
+    http_status: StatusCode,
+    body: String,
+    rtt: Option<Duration>,
+}
+
+
+impl CamResponse {
+    pub(crate) async fn read(res: Response) -> Result<Self, reqwest::Error> {
+        let rtt = res.extensions().get::<RoundTrip>().map(|rtt| rtt.0);
+
+        Ok(Self {
+            http_status: res.status(),
+            body: res.text().await?,
+            rtt,
+        })
+    }
+
+    /// Time of the HTTP exchange itself, see `Cam::rotate_cam_timed`
+    pub(crate) fn rtt(&self) -> Option<Duration> {
+        self.rtt
+    }
+
+    /// Whether the camera accepted the command, the HTTP status is a success and `<statusCode>` is 1 if it is there
+    pub fn is_ok(&self) -> bool {
+        self.http_status.is_success() && self.status_code().is_none_or(|code| code == 1)
+    }
+
+    pub fn http_status(&self) -> StatusCode {
+        self.http_status
+    }
+
+    /// `<statusCode>` of the `<ResponseStatus>`, 1 means OK. `None` if the answer does not have it
+    pub fn status_code(&self) -> Option<u32> {
+        xml::parse_tag(&self.body, "statusCode")
+    }
+
+    /// `<statusString>` of the `<ResponseStatus>`, e.g. `OK` or `Device Busy`. `None` if the answer does not have it
+    pub fn status_string(&self) -> Option<&str> {
+        xml::tag_value(&self.body, "statusString")
+    }
+
+    /// Body of the answer as it came from the camera
+    pub fn raw(&self) -> &str {
+        &self.body
+    }
+
+    pub fn into_raw(self) -> String {
+        self.body
+    }
+}
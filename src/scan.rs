@@ -0,0 +1,133 @@
+//! Sweeping the camera back and forth along the horizon
+use std::time::Duration;
+use crate::{capabilities::Range, error, Cam, CamResponse};
+
+
+/// How often the position is read to find out that the camera reached the edge of the scan
+const SCAN_POLL: Duration = Duration::from_millis(200);
+/// Tenths of a degree in a full turn
+const FULL_TURN: i32 = 3600;
+
+
+/// Handle of the scan started with `Cam::horizontal_scan`
+#[derive(Debug)]
+pub struct ScanHandle {
+    task: tokio::task::JoinHandle<()>,
+    cam: Option<Cam>,
+}
+
+
+impl ScanHandle {
+    /// Stop the scan and the camera
+    pub async fn stop(mut self) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        self.task.abort();
+
+        match self.cam.take() {
+            Some(mut cam) => cam.stop_continuous().await,
+            None => unreachable!("the camera is taken only by `stop` and `drop`"),
+        }
+    }
+}
+
+
+impl Drop for ScanHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+
+        let Some(mut cam) = self.cam.take() else {
+            return;
+        };
+
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("the scan was dropped outside a tokio runtime, the camera may still be panning");
+            return;
+        };
+
+        runtime.spawn(async move {
+            let _ = cam.stop_continuous().await;
+        });
+    }
+}
+
+
+impl Cam {
+    /// Sweep the camera between the azimuths `left` and `right` in tenths of a degree, like `PtzStatus::azimuth`, until
+    /// the returned handle is stopped or dropped. The camera pans clockwise from `left` to `right`, so the sweep may cross
+    /// the zero azimuth. `speed` is the continuous pan speed from 1..=100. The scan runs on a background task using
+    /// a copy of the camera, outside a tokio runtime `NoRuntimeError` is returned
+    pub fn horizontal_scan(&mut self, left: i16, right: i16, speed: u8) -> Result<ScanHandle, Box<dyn std::error::Error>> {
+        let azimuth_range = Range { min: 0, max: FULL_TURN };
+        for (field, value) in [("left", left as i32), ("right", right as i32)] {
+            if !azimuth_range.contains(value) {
+                return Err(Box::new(error::OutOfRangeValueError::new(field, value, azimuth_range)));
+            }
+        }
+
+        let speed_range = Range { min: 1, max: 100 };
+        if !speed_range.contains(speed as i32) {
+            return Err(Box::new(error::OutOfRangeValueError::new("speed", speed as i32, speed_range)));
+        }
+
+        let left = left as i32;
+        let span = (right as i32 - left).rem_euclid(FULL_TURN);
+        // Offset from `left` in the direction of the scan, positions behind `left` are negative
+        let offset = move |azimuth: i32| {
+            let offset = (azimuth - left).rem_euclid(FULL_TURN);
+
+            if offset > span + (FULL_TURN - span) / 2 {
+                offset - FULL_TURN
+            } else {
+                offset
+            }
+        };
+
+        let runtime = tokio::runtime::Handle::try_current().map_err(|_| error::NoRuntimeError::new("horizontal scan"))?;
+        let mut cam = self.clone();
+        let speed = speed as i8;
+
+        let task = runtime.spawn(async move {
+            let mut clockwise = false;
+
+            loop {
+                // The raw velocity, the inversion and the curve of `start_continuous` would turn the sweep away from the edge
+                let pan = if clockwise { speed } else { -speed };
+                if cam.send_continuous(pan, 0, 0, None).await.is_err() {
+                    tokio::time::sleep(SCAN_POLL).await;
+                    continue;
+                }
+
+                loop {
+                    tokio::time::sleep(SCAN_POLL).await;
+
+                    let Ok(status) = cam.get_status().await else { continue };
+                    let offset = offset(status.azimuth);
+                    if (clockwise && offset >= span) || (!clockwise && offset <= 0) {
+                        break;
+                    }
+                }
+
+                clockwise = !clockwise;
+            }
+        });
+
+        Ok(ScanHandle {
+            task,
+            cam: Some(self.clone()),
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{builder::CamBuilder, error};
+
+
+    #[test]
+    fn scan_outside_a_runtime_is_an_error() {
+        let mut cam = CamBuilder::new("127.0.0.1", 80).build_unchecked().unwrap();
+
+        assert!(cam.horizontal_scan(0, 900, 50).unwrap_err().is::<error::NoRuntimeError>());
+    }
+}
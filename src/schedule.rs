@@ -0,0 +1,175 @@
+//! PTZ actions which the camera performs by itself at the scheduled time
+use crate::{error, xml, Cam, CamResponse};
+
+
+/// Day of the week
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [Self::Monday, Self::Tuesday, Self::Wednesday, Self::Thursday, Self::Friday, Self::Saturday, Self::Sunday];
+
+    fn number(&self) -> u8 {
+        Self::ALL.iter().position(|day| day == self).unwrap_or_default() as u8 + 1
+    }
+
+    fn from_number(number: u8) -> Option<Self> {
+        Self::ALL.get((number as usize).checked_sub(1)?).copied()
+    }
+}
+
+
+/// What the camera does during the scheduled block
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScheduledAction {
+    /// Go to the preset with this id
+    Preset(u16),
+    /// Run the patrol with this id
+    Patrol(u8),
+}
+
+
+/// Time block of the schedule, `start` and `end` are `(hour, minute)`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScheduleBlock {
+    pub day: Weekday,
+    pub start: (u8, u8),
+    pub end: (u8, u8),
+    pub action: ScheduledAction,
+}
+
+
+/// Weekly schedule of PTZ actions
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PtzSchedule {
+    pub enabled: bool,
+    pub blocks: Vec<ScheduleBlock>,
+}
+
+
+fn parse_time(time: &str) -> Option<(u8, u8)> {
+    let (hour, minute) = time.split_once(':')?;
+
+    Some((hour.parse().ok()?, minute.get(..2).unwrap_or(minute).parse().ok()?))
+}
+
+
+fn is_valid_time((hour, minute): (u8, u8)) -> bool {
+    (hour < 24 && minute < 60) || (hour, minute) == (24, 0)
+}
+
+
+impl PtzSchedule {
+    pub(crate) fn parse(body: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut blocks = Vec::new();
+
+        for day_block in xml::tag_values(body, "TimeTaskBlock") {
+            let day = xml::parse_tag(day_block, "dayOfWeek").and_then(Weekday::from_number).ok_or(error::ParseResponseError::new("dayOfWeek"))?;
+
+            for range in xml::tag_values(day_block, "TimeTaskRange") {
+                let number = xml::parse_tag(range, "Extend").ok_or(error::ParseResponseError::new("Extend"))?;
+                let action = match xml::tag_value(range, "TaskName") {
+                    Some("preset") => ScheduledAction::Preset(number),
+                    Some("patrol") => ScheduledAction::Patrol(number as u8),
+                    _ => continue,
+                };
+
+                blocks.push(ScheduleBlock {
+                    day,
+                    start: xml::tag_value(range, "beginTime").and_then(parse_time).ok_or(error::ParseResponseError::new("beginTime"))?,
+                    end: xml::tag_value(range, "endTime").and_then(parse_time).ok_or(error::ParseResponseError::new("endTime"))?,
+                    action,
+                });
+            }
+        }
+
+        Ok(Self {
+            enabled: xml::tag_value(body, "enabled") == Some("true"),
+            blocks,
+        })
+    }
+
+    pub(crate) fn to_xml(&self) -> String {
+        let days: String = Weekday::ALL.iter().map(|day| {
+            let ranges: String = self.blocks.iter().filter(|block| block.day == *day).enumerate().map(|(i, block)| {
+                let (name, number) = match block.action {
+                    ScheduledAction::Preset(id) => ("preset", id),
+                    ScheduledAction::Patrol(id) => ("patrol", id as u16),
+                };
+
+                format!("<TimeTaskRange>
+                        <TaskID>{}</TaskID>
+                        <beginTime>{:02}:{:02}</beginTime>
+                        <endTime>{:02}:{:02}</endTime>
+                        <Task>
+                            <TaskName>{}</TaskName>
+                            <Extend>{}</Extend>
+                        </Task>
+                    </TimeTaskRange>", i + 1, block.start.0, block.start.1, block.end.0, block.end.1, name, number)
+            }).collect();
+
+            format!("<TimeTaskBlock>
+                    <dayOfWeek>{}</dayOfWeek>
+                    {}
+                </TimeTaskBlock>", day.number(), ranges)
+        }).collect();
+
+        format!("<TimeTaskList>
+                <enabled>{}</enabled>
+                {}
+            </TimeTaskList>", self.enabled, days)
+    }
+}
+
+
+impl Cam {
+    /// Get the schedule of PTZ actions
+    pub async fn get_scheduled_task(&mut self) -> Result<PtzSchedule, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.ptz_url("timetasks"), "scheduled tasks").await?;
+
+        PtzSchedule::parse(&body)
+    }
+
+    /// Replace the schedule of PTZ actions. The times must be valid and every referenced preset or patrol must exist
+    pub async fn set_scheduled_task(&mut self, schedule: PtzSchedule) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        for block in schedule.blocks.iter() {
+            if !is_valid_time(block.start) || !is_valid_time(block.end) || block.start >= block.end {
+                return Err(Box::new(error::InvalidScheduleError::new(block.start, block.end)));
+            }
+        }
+
+        let presets = self.get_presets().await?;
+        let mut patrols = None;
+        for block in schedule.blocks.iter() {
+            match block.action {
+                ScheduledAction::Preset(id) => {
+                    if !presets.iter().any(|preset| preset.id == id) {
+                        return Err(Box::new(error::PresetNotFoundError::new(id)));
+                    }
+                },
+                ScheduledAction::Patrol(id) => {
+                    if patrols.is_none() {
+                        patrols = Some(self.get_patrol_ids().await?);
+                    }
+
+                    if !patrols.as_ref().is_some_and(|patrols| patrols.contains(&id)) {
+                        return Err(Box::new(error::PatrolNotFoundError::new(id)));
+                    }
+                },
+            }
+        }
+
+        let res = self.send(self.client.put(self.ptz_url("timetasks")).body(schedule.to_xml())).await?;
+
+        Self::check_supported(res, "scheduled tasks").await
+    }
+}
@@ -0,0 +1,71 @@
+//! RS-485 port through which the device drives analog PTZ cameras
+use crate::{error, xml, Cam, CamResponse};
+
+
+/// Baud rates the RS-485 port accepts
+pub const BAUD_RATES: [u32; 8] = [1200, 2400, 4800, 9600, 19200, 38400, 57600, 115200];
+
+
+/// Protocol which the device speaks to the PTZ over RS-485
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PtzProtocol {
+    PelcoD,
+    PelcoP,
+    Hikvision,
+    /// Any other protocol of the firmware, with the name as the device reports it
+    Other(String),
+}
+
+
+impl PtzProtocol {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::PelcoD => "PELCO-D",
+            Self::PelcoP => "PELCO-P",
+            Self::Hikvision => "HIKVISION",
+            Self::Other(name) => name,
+        }
+    }
+
+    fn parse(name: &str) -> Self {
+        match name {
+            "PELCO-D" => Self::PelcoD,
+            "PELCO-P" => Self::PelcoP,
+            "HIKVISION" => Self::Hikvision,
+            _ => Self::Other(name.to_string()),
+        }
+    }
+}
+
+
+impl Cam {
+    fn serial_url(&self) -> String {
+        format!("{}/ISAPI/System/Serial/ports/1", self.host)
+    }
+
+    /// Get the PTZ protocol and the baud rate of the RS-485 port
+    pub async fn ptz_protocol(&mut self) -> Result<(PtzProtocol, u32), Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.serial_url(), "serial port").await?;
+
+        Ok((
+            PtzProtocol::parse(xml::tag_value(&body, "protocol").ok_or(error::ParseResponseError::new("protocol"))?),
+            xml::parse_tag(&body, "baudRate").ok_or(error::ParseResponseError::new("baudRate"))?,
+        ))
+    }
+
+    /// Change the PTZ protocol and the baud rate of the RS-485 port, `baud` must be one of [`BAUD_RATES`].
+    /// Other settings of the port are kept as they are
+    pub async fn set_ptz_protocol(&mut self, proto: PtzProtocol, baud: u32) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        if !BAUD_RATES.contains(&baud) {
+            return Err(Box::new(error::NotAllowedValueError::new("baudRate", baud as i32, BAUD_RATES.iter().map(|rate| *rate as i32).collect())));
+        }
+
+        let config = self.get_xml(self.serial_url(), "serial port").await?;
+        let config = xml::replace_tag(&config, "protocol", &xml::escape(proto.as_str())).ok_or(error::ParseResponseError::new("protocol"))?;
+        let config = xml::replace_tag(&config, "baudRate", &baud.to_string()).ok_or(error::ParseResponseError::new("baudRate"))?;
+
+        let res = self.send(self.client.put(self.serial_url()).body(config)).await?;
+
+        Self::check_supported(res, "serial port").await
+    }
+}
@@ -0,0 +1,40 @@
+//! Smart detection features of the camera, e.g. line crossing and intrusion
+use crate::{xml, Cam};
+
+
+/// Smart detections of the channel, `Some(enabled)` for a supported detection, `None` if the camera does not support it
+/// or its state can not be read
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SmartEvents {
+    pub line_crossing: Option<bool>,
+    /// Intrusion into a region, called field detection in ISAPI
+    pub intrusion: Option<bool>,
+    pub region_entrance: Option<bool>,
+    pub region_exiting: Option<bool>,
+}
+
+
+impl Cam {
+    async fn smart_event_state(&mut self, capabilities: &str, capability: &str, path: &str) -> Option<bool> {
+        if xml::tag_value(capabilities, capability) != Some("true") {
+            return None;
+        }
+
+        let body = self.get_xml(format!("{}/ISAPI/Smart/{}/{}", self.host, path, self.channel), "smart events").await.ok()?;
+
+        Some(xml::tag_value(&body, "enabled")? == "true")
+    }
+
+    /// Smart detections which the camera supports and whether they are enabled. The Smart API differs a lot between
+    /// the firmwares, so anything the camera does not answer clearly is `None`
+    pub async fn smart_events(&mut self) -> Result<SmartEvents, Box<dyn std::error::Error>> {
+        let capabilities = self.get_xml(format!("{}/ISAPI/Smart/capabilities", self.host), "smart events").await?;
+
+        Ok(SmartEvents {
+            line_crossing: self.smart_event_state(&capabilities, "isSupportLineDetection", "LineDetection").await,
+            intrusion: self.smart_event_state(&capabilities, "isSupportFieldDetection", "FieldDetection").await,
+            region_entrance: self.smart_event_state(&capabilities, "isSupportRegionEntrance", "regionEntrance").await,
+            region_exiting: self.smart_event_state(&capabilities, "isSupportRegionExiting", "regionExiting").await,
+        })
+    }
+}
@@ -0,0 +1,30 @@
+//! Acceleration and smoothing of movements done by the camera itself
+use crate::{capabilities::Range, error, Cam, CamResponse};
+
+
+/// Used when the camera does not report the smoothing range in its capabilities
+const DEFAULT_SMOOTHING_RANGE: Range = Range { min: 0, max: 9 };
+
+
+impl Cam {
+    /// Set the level of smoothing which the camera applies to the start and the end of movements, 0 disables it.
+    /// The level must lie in the range from the capabilities of the camera
+    pub async fn set_ptz_smoothing(&mut self, level: u8) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let range = if self.capabilities_available {
+            self.get_capabilities().await?.smoothing
+        } else {
+            None
+        }.unwrap_or(DEFAULT_SMOOTHING_RANGE);
+
+        if !range.contains(level as i32) {
+            return Err(Box::new(error::OutOfRangeValueError::new("smoothing", level as i32, range)));
+        }
+
+        let res = self.send(self.client.put(self.ptz_url("smoothing")).body(format!("<PTZSmoothing>
+                <enabled>{}</enabled>
+                <level>{}</level>
+            </PTZSmoothing>", level != 0, level))).await?;
+
+        Self::check_supported(res, "smoothing").await
+    }
+}
@@ -0,0 +1,90 @@
+//! Statistics of the requests sent to the camera, useful for health dashboards
+use std::{sync::{Arc, Mutex}, time::{Duration, Instant}};
+use crate::{Cam, MIN_MOVEMENT_SPEED};
+
+
+/// Counters of the requests sent to the camera, clones of `Cam` share them
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionStats {
+    /// When the camera last answered with a success status
+    pub last_success: Option<Instant>,
+    /// Requests which the camera answered with a success status
+    pub successes: u64,
+    /// Requests which failed to be sent or were answered with an error status
+    pub failures: u64,
+}
+
+
+/// Number of status reads made by `calibrate`
+const CALIBRATION_SAMPLES: usize = 5;
+
+
+/// Result of `calibrate`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Calibration {
+    /// Median round-trip time of the status reads
+    pub median_rtt: Duration,
+    /// Shortest movement speed in milliseconds with which the commands do not overtake the answers of the camera,
+    /// twice the median round-trip time plus the 50ms the throttle tolerates, but not less than `MIN_MOVEMENT_SPEED`
+    pub suggested_movement_speed: usize,
+}
+
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SharedStats(Arc<Mutex<ConnectionStats>>);
+
+
+impl SharedStats {
+    pub(crate) fn update(&self, success: bool) {
+        let mut stats = self.0.lock().unwrap_or_else(|err| err.into_inner());
+
+        if success {
+            stats.last_success = Some(Instant::now());
+            stats.successes += 1;
+        } else {
+            stats.failures += 1;
+        }
+    }
+
+    fn get(&self) -> ConnectionStats {
+        *self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+
+impl Cam {
+    /// When the camera last answered a request with a success status, `None` if it has not happened yet
+    pub fn last_success(&self) -> Option<Instant> {
+        self.stats.get().last_success
+    }
+
+    /// Counters of the successful and failed requests
+    pub fn stats(&self) -> ConnectionStats {
+        self.stats.get()
+    }
+
+    /// Measure the round-trip time to the camera with a few status reads and suggest the movement speed for this network.
+    /// The camera does not move. If `apply` is true, the suggested value replaces the current movement speed
+    pub async fn calibrate(&mut self, apply: bool) -> Result<Calibration, Box<dyn std::error::Error>> {
+        let mut samples = Vec::with_capacity(CALIBRATION_SAMPLES);
+
+        for _ in 0..CALIBRATION_SAMPLES {
+            let start = Instant::now();
+            self.get_status().await?;
+            samples.push(start.elapsed());
+        }
+
+        samples.sort();
+        let median_rtt = samples[CALIBRATION_SAMPLES / 2];
+        let calibration = Calibration {
+            median_rtt,
+            suggested_movement_speed: (median_rtt.as_millis() as usize * 2 + 50).max(MIN_MOVEMENT_SPEED),
+        };
+
+        if apply {
+            self.change_movement_speed(calibration.suggested_movement_speed).await?;
+        }
+
+        Ok(calibration)
+    }
+}
@@ -0,0 +1,125 @@
+//! Reading the current position of the camera
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use crate::{error, xml, Cam};
+
+
+/// Current absolute position of the camera as reported by `/status`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PtzStatus {
+    /// Horizontal position in tenths of a degree
+    pub azimuth: i32,
+    /// Vertical position in tenths of a degree
+    pub elevation: i32,
+    /// Zoom factor multiplied by ten
+    pub absolute_zoom: i32,
+}
+
+
+impl PtzStatus {
+    pub(crate) fn parse(body: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self {
+            azimuth: xml::parse_tag(body, "azimuth").ok_or(error::ParseResponseError::new("azimuth"))?,
+            elevation: xml::parse_tag(body, "elevation").ok_or(error::ParseResponseError::new("elevation"))?,
+            absolute_zoom: xml::parse_tag(body, "absoluteZoom").ok_or(error::ParseResponseError::new("absoluteZoom"))?,
+        })
+    }
+}
+
+
+/// Current position of the lens, the fields are `None` if the firmware does not report them
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LensStatus {
+    pub focus: Option<i32>,
+    pub iris: Option<i32>,
+}
+
+
+impl LensStatus {
+    pub(crate) fn parse(body: &str) -> Self {
+        Self {
+            focus: xml::parse_tag(body, "focus"),
+            iris: xml::parse_tag(body, "iris"),
+        }
+    }
+}
+
+
+impl Cam {
+    /// Get the current position of the camera
+    pub async fn get_status(&mut self) -> Result<PtzStatus, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.ptz_url("status"), "status").await?;
+
+        PtzStatus::parse(&body)
+    }
+
+    /// Get the preset at which the camera currently stands, `None` if it is not at any saved preset
+    pub async fn current_preset(&mut self) -> Result<Option<u16>, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.ptz_url("status"), "status").await?;
+
+        Ok(xml::parse_tag(&body, "presetID").filter(|id| *id != 0))
+    }
+
+    /// Get the current focus and iris positions. If the firmware has no such endpoint, `UnsupportedFeatureError` is returned
+    pub async fn get_lens_status(&mut self) -> Result<LensStatus, Box<dyn std::error::Error>> {
+        let url = format!("{}/ISAPI/System/Video/inputs/channels/{}/focusStatus", self.host, self.channel);
+        let body = self.get_xml(url, "lens status").await?;
+
+        Ok(LensStatus::parse(&body))
+    }
+
+    /// Find out whether the camera is moving right now. If the status of the camera has no field for it, the position is
+    /// read twice with the sampling interval between the reads, see `set_moving_sample_interval`. A longer interval
+    /// notices slower movements but answers later
+    #[cfg(feature = "tokio")]
+    pub async fn is_moving(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+        let body = self.get_xml(self.ptz_url("status"), "status").await?;
+        if let Some(moving) = xml::tag_value(&body, "isMoving") {
+            return Ok(moving == "true");
+        }
+
+        let first = PtzStatus::parse(&body)?;
+        tokio::time::sleep(std::time::Duration::from_millis(self.moving_sample_interval as u64)).await;
+
+        Ok(self.get_status().await? != first)
+    }
+
+    /// Change the interval between the two position reads of `is_moving`, 200ms by default
+    pub async fn set_moving_sample_interval(&mut self, ms: usize) {
+        self.moving_sample_interval = ms;
+    }
+
+    #[cfg(feature = "tokio")]
+    async fn read_back(&mut self, delay_ms: Option<usize>) -> Result<PtzStatus, Box<dyn std::error::Error>> {
+        let delay = delay_ms.unwrap_or(self.movement_speed);
+        tokio::time::sleep(std::time::Duration::from_millis(delay as u64)).await;
+
+        self.get_status().await
+    }
+
+    /// Rotate the camera and return its position after the move. Before reading the position it waits `delay_ms`,
+    /// if it is `None` the movement speed is waited so that the move has time to finish
+    #[cfg(feature = "tokio")]
+    pub async fn rotate_cam_verified(&mut self, rot: i8, delay_ms: Option<usize>) -> Result<PtzStatus, Box<dyn std::error::Error>> {
+        self.rotate_cam(rot).await?;
+
+        self.read_back(delay_ms).await
+    }
+
+    /// Same as `rotate_cam_verified`, but for `tilt_cam`
+    #[cfg(feature = "tokio")]
+    pub async fn tilt_cam_verified(&mut self, til: i8, delay_ms: Option<usize>) -> Result<PtzStatus, Box<dyn std::error::Error>> {
+        self.tilt_cam(til).await?;
+
+        self.read_back(delay_ms).await
+    }
+
+    /// Same as `rotate_cam_verified`, but for `zoom_cam`
+    #[cfg(feature = "tokio")]
+    pub async fn zoom_cam_verified(&mut self, zoom: i8, delay_ms: Option<usize>) -> Result<PtzStatus, Box<dyn std::error::Error>> {
+        self.zoom_cam(zoom).await?;
+
+        self.read_back(delay_ms).await
+    }
+}
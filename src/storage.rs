@@ -0,0 +1,40 @@
+//! Local storage of the camera, usually an SD card used for the edge recording
+use crate::{xml, Cam};
+
+
+/// State of the SD card or the disk of the camera, the fields are `None` if the firmware does not report them
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageStatus {
+    /// Whether any card or disk is inserted
+    pub present: bool,
+    pub capacity_mb: Option<u64>,
+    pub free_mb: Option<u64>,
+    /// Health as reported by the camera, e.g. `ok`, `unformatted` or `error`
+    pub health: Option<String>,
+}
+
+
+impl StorageStatus {
+    pub(crate) fn parse(body: &str) -> Self {
+        let Some(hdd) = xml::tag_values(body, "hdd").into_iter().next() else {
+            return Self::default();
+        };
+
+        Self {
+            present: true,
+            capacity_mb: xml::parse_tag(hdd, "capacity"),
+            free_mb: xml::parse_tag(hdd, "freeSpace"),
+            health: xml::tag_value(hdd, "status").map(String::from),
+        }
+    }
+}
+
+
+impl Cam {
+    /// Get the state of the first SD card or disk of the camera, `present` is `false` if there is none
+    pub async fn storage_status(&mut self) -> Result<StorageStatus, Box<dyn std::error::Error>> {
+        let body = self.get_xml(format!("{}/ISAPI/ContentMgmt/Storage", self.host), "storage").await?;
+
+        Ok(StorageStatus::parse(&body))
+    }
+}
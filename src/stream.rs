@@ -0,0 +1,206 @@
+//! Video streams of the camera
+use crate::{capabilities::Range, error, xml, Cam, CamResponse};
+
+
+/// Frame rates which fit `maxFrameRate`, the camera keeps it in hundredths of a frame per second
+const FRAMERATE_RANGE: Range = Range { min: 0, max: (u32::MAX / 100) as i32 };
+
+
+/// Video stream of the channel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StreamType {
+    Main,
+    Sub,
+    Third,
+}
+
+
+impl StreamType {
+    fn id(&self, channel: u32) -> u32 {
+        channel * 100 + match self {
+            Self::Main => 1,
+            Self::Sub => 2,
+            Self::Third => 3,
+        }
+    }
+
+    fn from_id(id: u32) -> Option<Self> {
+        match id % 100 {
+            1 => Some(Self::Main),
+            2 => Some(Self::Sub),
+            3 => Some(Self::Third),
+            _ => None,
+        }
+    }
+}
+
+
+/// Video stream as listed by `list_streams`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamInfo {
+    /// Id of the stream in ISAPI, e.g. 101 for the main stream of the first channel
+    pub id: u32,
+    /// Video channel the stream belongs to
+    pub channel: u32,
+    /// `None` for the streams beyond the third one, which some NVRs expose
+    pub stream: Option<StreamType>,
+    pub name: String,
+    /// Codec as reported by the camera, e.g. `H.264` or `H.265`
+    pub codec: String,
+    pub resolution: (u16, u16),
+}
+
+
+impl StreamInfo {
+    pub(crate) fn parse_list(body: &str) -> Result<Vec<Self>, Box<dyn std::error::Error>> {
+        let mut streams = Vec::new();
+
+        for channel in xml::tag_values(body, "StreamingChannel") {
+            let id: u32 = xml::parse_tag(channel, "id").ok_or(error::ParseResponseError::new("id"))?;
+            let video = xml::tag_value(channel, "Video").ok_or(error::ParseResponseError::new("Video"))?;
+
+            streams.push(Self {
+                id,
+                channel: id / 100,
+                stream: StreamType::from_id(id),
+                name: xml::tag_value(channel, "channelName").unwrap_or_default().to_string(),
+                codec: xml::tag_value(video, "videoCodecType").unwrap_or_default().to_string(),
+                resolution: (
+                    xml::parse_tag(video, "videoResolutionWidth").ok_or(error::ParseResponseError::new("videoResolutionWidth"))?,
+                    xml::parse_tag(video, "videoResolutionHeight").ok_or(error::ParseResponseError::new("videoResolutionHeight"))?,
+                ),
+            });
+        }
+
+        Ok(streams)
+    }
+}
+
+
+impl Cam {
+    pub(crate) fn streaming_url(&self, stream: StreamType) -> String {
+        format!("{}/ISAPI/Streaming/channels/{}", self.host, stream.id(self.channel))
+    }
+
+    /// Get all video streams of the device with their codec and resolution. Cameras which have only the main and the sub
+    /// stream list just those two
+    pub async fn list_streams(&mut self) -> Result<Vec<StreamInfo>, Box<dyn std::error::Error>> {
+        let body = self.get_xml(format!("{}/ISAPI/Streaming/channels", self.host), "streaming").await?;
+
+        StreamInfo::parse_list(&body)
+    }
+
+    /// Change the resolution and the bitrate of the `stream`, other settings of the stream are kept as they are
+    pub async fn set_stream_params(&mut self, stream: StreamType, resolution: (u16, u16), bitrate_kbps: u32) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let url = self.streaming_url(stream);
+        let config = self.get_xml(url.clone(), "streaming").await?;
+
+        let mut config = xml::replace_tag(&config, "videoResolutionWidth", &resolution.0.to_string()).ok_or(error::ParseResponseError::new("videoResolutionWidth"))?;
+        config = xml::replace_tag(&config, "videoResolutionHeight", &resolution.1.to_string()).ok_or(error::ParseResponseError::new("videoResolutionHeight"))?;
+
+        let bitrate = bitrate_kbps.to_string();
+        let mut bitrate_set = false;
+        for tag in ["constantBitRate", "vbrUpperCap"] {
+            if let Some(replaced) = xml::replace_tag(&config, tag, &bitrate) {
+                config = replaced;
+                bitrate_set = true;
+            }
+        }
+
+        if !bitrate_set {
+            return Err(Box::new(error::ParseResponseError::new("constantBitRate")));
+        }
+
+        let res = self.send(self.client.put(url).body(config)).await?;
+
+        Self::check_supported(res, "streaming").await
+    }
+
+    async fn stream_config_tag(&mut self, stream: StreamType, tag: &'static str) -> Result<u32, Box<dyn std::error::Error>> {
+        let config = self.get_xml(self.streaming_url(stream), "streaming").await?;
+
+        Ok(xml::parse_tag(&config, tag).ok_or(error::ParseResponseError::new(tag))?)
+    }
+
+    async fn set_stream_config_tag(&mut self, stream: StreamType, tag: &'static str, value: u32) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let url = self.streaming_url(stream);
+        let config = self.get_xml(url.clone(), "streaming").await?;
+        let config = xml::replace_tag(&config, tag, &value.to_string()).ok_or(error::ParseResponseError::new(tag))?;
+
+        let res = self.send(self.client.put(url).body(config)).await?;
+
+        Self::check_supported(res, "streaming").await
+    }
+
+    /// Get the frame rate of the `stream` in frames per second
+    pub async fn get_framerate(&mut self, stream: StreamType) -> Result<u32, Box<dyn std::error::Error>> {
+        Ok(self.stream_config_tag(stream, "maxFrameRate").await? / 100)
+    }
+
+    /// Change the frame rate of the `stream`, `fps` must be one of the frame rates the camera offers for the stream, if it reports them.
+    /// Other settings of the stream are kept as they are
+    pub async fn set_framerate(&mut self, stream: StreamType, fps: u32) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let capabilities = self.get_optional_xml(format!("{}/capabilities", self.streaming_url(stream)), "streaming").await?;
+
+        if let Some(options) = xml::tag_attr(&capabilities, "maxFrameRate", "opt") {
+            let allowed: Vec<i32> = options.split(',').filter_map(|rate| rate.trim().parse::<i32>().ok()).map(|rate| rate / 100).collect();
+
+            if !allowed.contains(&(fps as i32)) {
+                return Err(Box::new(error::NotAllowedValueError::new("maxFrameRate", fps as i32, allowed)));
+            }
+        }
+
+        let rate = fps.checked_mul(100).ok_or(error::OutOfRangeValueError::new("maxFrameRate", i32::try_from(fps).unwrap_or(i32::MAX), FRAMERATE_RANGE))?;
+
+        self.set_stream_config_tag(stream, "maxFrameRate", rate).await
+    }
+
+    /// Get the GOP size of the `stream`, the number of frames between two key frames
+    pub async fn get_gop(&mut self, stream: StreamType) -> Result<u32, Box<dyn std::error::Error>> {
+        self.stream_config_tag(stream, "GovLength").await
+    }
+
+    /// Change the GOP size of the `stream`, it must lie in the range the camera reports for the stream, if it reports one.
+    /// Other settings of the stream are kept as they are
+    pub async fn set_gop(&mut self, stream: StreamType, gop: u32) -> Result<CamResponse, Box<dyn std::error::Error>> {
+        let capabilities = self.get_optional_xml(format!("{}/capabilities", self.streaming_url(stream)), "streaming").await?;
+
+        let min = xml::tag_attr(&capabilities, "GovLength", "min").and_then(|min| min.parse().ok());
+        let max = xml::tag_attr(&capabilities, "GovLength", "max").and_then(|max| max.parse().ok());
+        if let (Some(min), Some(max)) = (min, max) {
+            let range = Range { min, max };
+
+            if !range.contains(gop as i32) {
+                return Err(Box::new(error::OutOfRangeValueError::new("GovLength", gop as i32, range)));
+            }
+        }
+
+        self.set_stream_config_tag(stream, "GovLength", gop).await
+    }
+
+    /// Get the resolutions offered by the main stream, which are the sizes `snapshot` can take as well.
+    /// Empty if the camera does not list them
+    pub async fn snapshot_resolutions(&mut self) -> Result<Vec<(u16, u16)>, Box<dyn std::error::Error>> {
+        let capabilities = self.get_xml(format!("{}/capabilities", self.streaming_url(StreamType::Main)), "streaming").await?;
+
+        let options = |tag: &str| -> Vec<u16> {
+            xml::tag_attr(&capabilities, tag, "opt").unwrap_or_default().split(',').filter_map(|size| size.trim().parse().ok()).collect()
+        };
+
+        Ok(options("videoResolutionWidth").into_iter().zip(options("videoResolutionHeight")).collect())
+    }
+
+    /// Take a JPEG picture from the main stream. If `resolution` is `None`, the camera uses the resolution of the stream,
+    /// otherwise it should be one of `snapshot_resolutions`
+    pub async fn snapshot(&mut self, resolution: Option<(u16, u16)>) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let mut request = self.client.get(format!("{}/picture", self.streaming_url(StreamType::Main)));
+        if let Some((width, height)) = resolution {
+            request = request.query(&[("videoResolutionWidth", width), ("videoResolutionHeight", height)]);
+        }
+
+        let res = Self::check_status(self.send(request).await?, "snapshot").await?;
+        let picture = res.bytes().await?;
+
+        Ok(picture.to_vec())
+    }
+}
@@ -0,0 +1,54 @@
+//! Tour over presets run by the library, without saving a patrol on the camera
+use std::time::Duration;
+use crate::{error, Cam};
+
+
+/// Handle of the tour started with `Cam::tour`, the tour stops when it is released or dropped
+#[derive(Debug)]
+pub struct TourHandle {
+    task: tokio::task::JoinHandle<()>,
+}
+
+
+impl TourHandle {
+    /// Stop the tour, the camera stays at the preset it reached last
+    pub fn release(self) {}
+}
+
+
+impl Drop for TourHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+
+impl Cam {
+    /// Go over the `stops` in a loop until the returned handle is released: move to the preset of every stop and stay
+    /// there for its dwell. A dwell shorter than the movement speed is extended to it, so the presets are not requested
+    /// faster than the throttle allows. Unlike `start_patrol` nothing is saved on the camera, the tour runs on
+    /// a background task using a copy of the camera, outside a tokio runtime `NoRuntimeError` is returned
+    pub fn tour(&mut self, stops: Vec<(u16, Duration)>) -> Result<TourHandle, error::NoRuntimeError> {
+        let runtime = tokio::runtime::Handle::try_current().map_err(|_| error::NoRuntimeError::new("tour"))?;
+        let mut cam = self.clone();
+        let min_dwell = Duration::from_millis(self.movement_speed as u64);
+
+        let task = runtime.spawn(async move {
+            if stops.is_empty() {
+                return;
+            }
+
+            loop {
+                for (preset, dwell) in &stops {
+                    let _ = cam.goto_preset(*preset).await;
+
+                    tokio::time::sleep((*dwell).max(min_dwell)).await;
+                }
+            }
+        });
+
+        Ok(TourHandle {
+            task,
+        })
+    }
+}
@@ -0,0 +1,140 @@
+//! Tiny helpers for pulling values out of the XML answers of the camera
+
+
+/// Returns the text inside the first `<tag>...</tag>` element, attributes of the opening tag are skipped
+pub(crate) fn tag_value<'a>(body: &'a str, tag: &str) -> Option<&'a str> {
+    tag_values(body, tag).into_iter().next()
+}
+
+
+/// Returns the text inside every `<tag>...</tag>` element in the order they appear
+pub(crate) fn tag_values<'a>(body: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut values = Vec::new();
+    let mut rest = body;
+
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+
+        match after.chars().next() {
+            Some('>') | Some(' ') | Some('\t') | Some('\r') | Some('\n') => {},
+            _ => {
+                rest = after;
+                continue;
+            },
+        }
+
+        let Some(content_start) = after.find('>') else { break };
+        let content = &after[content_start + 1..];
+        let Some(end) = content.find(&close) else { break };
+
+        values.push(content[..end].trim());
+        rest = &content[end + close.len()..];
+    }
+
+    values
+}
+
+
+/// Parses the text of the first `<tag>` element into `T`
+pub(crate) fn parse_tag<T: std::str::FromStr>(body: &str, tag: &str) -> Option<T> {
+    tag_value(body, tag)?.parse().ok()
+}
+
+
+/// Replaces the text inside the first `<tag>...</tag>` element, `None` is returned if there is no such element
+pub(crate) fn replace_tag(body: &str, tag: &str, value: &str) -> Option<String> {
+    let current = tag_value(body, tag)?;
+    let start = current.as_ptr() as usize - body.as_ptr() as usize;
+
+    Some(format!("{}{}{}", &body[..start], value, &body[start + current.len()..]))
+}
+
+
+/// Escapes the text given by the user so that it can be put inside an element of the body, e.g. a preset name
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+
+/// Reverses `escape` for the text read from an answer of the camera
+pub(crate) fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+
+/// Returns the value of the attribute `attr` of the first `<tag>` element
+pub(crate) fn tag_attr<'a>(body: &'a str, tag: &str, attr: &str) -> Option<&'a str> {
+    let open = format!("<{} ", tag);
+    let start = body.find(&open)? + open.len();
+    let attrs = &body[start..start + body[start..].find('>')?];
+
+    let pattern = format!("{}=\"", attr);
+    let value_start = attrs.find(&pattern)? + pattern.len();
+
+    Some(&attrs[value_start..value_start + attrs[value_start..].find('"')?])
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn escapes_less_than() {
+        assert_eq!(escape("a<b"), "a&lt;b");
+        assert_eq!(unescape("a&lt;b"), "a<b");
+    }
+
+    #[test]
+    fn escapes_greater_than() {
+        assert_eq!(escape("a>b"), "a&gt;b");
+        assert_eq!(unescape("a&gt;b"), "a>b");
+    }
+
+    #[test]
+    fn escapes_ampersand() {
+        assert_eq!(escape("a&b"), "a&amp;b");
+        assert_eq!(unescape("a&amp;b"), "a&b");
+    }
+
+    #[test]
+    fn escapes_double_quote() {
+        assert_eq!(escape("a\"b"), "a&quot;b");
+        assert_eq!(unescape("a&quot;b"), "a\"b");
+    }
+
+    #[test]
+    fn escapes_single_quote() {
+        assert_eq!(escape("a'b"), "a&apos;b");
+        assert_eq!(unescape("a&apos;b"), "a'b");
+    }
+
+    #[test]
+    fn unescape_reverses_escape() {
+        let text = "<preset name=\"Gate & 'Yard'\">";
+
+        assert_eq!(unescape(&escape(text)), text);
+        // An escaped entity in the text itself must come back as it was typed, not decoded twice
+        assert_eq!(unescape(&escape("&lt;")), "&lt;");
+    }
+}